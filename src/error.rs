@@ -0,0 +1,39 @@
+use crate::fmt;
+
+/// The error type returned by the fallible `try_*` methods of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IterExtdError {
+    /// A size, step or chunk length is zero.
+    ZeroSize,
+    /// A region does not fit within the bounds of the target area.
+    OutOfBounds,
+    /// A value does not fit into the selected intermediate type.
+    Overflow,
+    /// The iterator yielded fewer elements than required.
+    TooShort,
+    /// The lower bound of a range is greater than the upper bound.
+    InvalidRange,
+    /// More distinct elements were found than fit into a fixed capacity.
+    CapacityExceeded,
+    /// A parameter of a scaling curve is not valid.
+    InvalidCurve,
+}
+
+impl fmt::Display for IterExtdError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            IterExtdError::ZeroSize => "size must be non-zero",
+            IterExtdError::OutOfBounds => "offset and size exceed the bounds",
+            IterExtdError::Overflow => "overflow of the selected intermediate type",
+            IterExtdError::TooShort => "iterator is shorter than required",
+            IterExtdError::InvalidRange => "lower range bound must be less than upper",
+            IterExtdError::CapacityExceeded => "number of distinct elements exceeds the capacity",
+            IterExtdError::InvalidCurve => "invalid parameter of the scaling curve",
+        };
+        fmt.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for IterExtdError {}
@@ -1,20 +1,19 @@
 use crate::FusedIterator;
+use crate::IterExtdError;
 use crate::Itertools;
 use crate::PhantomData;
+use crate::Rounding;
 use crate::TryFromByAdd;
-use crate::{one, zero, Bounded, CheckedMul, CheckedSub, One, Zero};
+use crate::{zero, Bounded, CheckedMul, CheckedSub, One, Zero};
 use crate::{Add, Div, Mul, Sub};
+use crate::{Float, NumCast};
 use crate::{MinMax, NoElements, OneElement};
 
 /// Structure that stores data, parameters for the [`scaling`](Scaler::scaling) iterator adapter.
 #[derive(Debug, Clone)]
 pub struct Scaling<I: Iterator, U, R> {
-    denominator: U,
-    numerator: U,
-    input_start: U,
-    output_start: U,
+    map: ScaleMap<U, R>,
     iter: I,
-    phantom: PhantomData<R>,
 }
 impl<I, U, R> Scaling<I, U, R>
 where
@@ -25,7 +24,7 @@ where
         + convert_by_add::FromByAddAll<I::Item>
         + CheckedMul
         + CheckedSub
-        + PartialEq
+        + PartialOrd
         + One
         + Zero
         + Copy,
@@ -33,28 +32,37 @@ where
 {
     #[inline]
     pub(crate) fn new(iter: I, output_range: impl convert_by_add::RangeBoundsInner<R>) -> Self {
+        Self::try_new(iter, output_range).unwrap_or_else(|err| match err {
+            IterExtdError::InvalidRange => panic!("lower range bound must be less than upper"),
+            _ => panic!("overflow of the selected intermediate type"),
+        })
+    }
+
+    #[inline]
+    pub(crate) fn try_new(
+        iter: I,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> Result<Self, IterExtdError> {
         let output_start = U::try_from_by_add(match output_range.start_bound() {
             convert_by_add::BoundInner::Included(&start) => start,
             convert_by_add::BoundInner::Unbounded => <R>::min_value(),
         })
-        .expect("overflow of the selected intermediate type");
+        .ok_or(IterExtdError::Overflow)?;
 
         let output_end = U::try_from_by_add(match output_range.end_bound() {
             convert_by_add::BoundInner::Included(&end) => end,
             convert_by_add::BoundInner::Unbounded => <R>::max_value(),
         })
-        .expect("overflow of the selected intermediate type");
+        .ok_or(IterExtdError::Overflow)?;
 
         let (min, max) = match iter.clone().minmax() {
             NoElements => (zero::<U>(), zero::<U>()),
             OneElement(max) => {
-                let max_converted =
-                    U::try_from_by_add(max).expect("overflow of the selected intermediate type");
+                let max_converted = U::try_from_by_add(max).ok_or(IterExtdError::Overflow)?;
                 (zero::<U>(), max_converted)
             }
             MinMax(min, max) => {
-                let max_converted =
-                    U::try_from_by_add(max).expect("overflow of the selected intermediate type");
+                let max_converted = U::try_from_by_add(max).ok_or(IterExtdError::Overflow)?;
                 if min == max {
                     (zero::<U>(), max_converted)
                 } else {
@@ -63,23 +71,18 @@ where
             }
         };
 
-        let _ = max
-            .checked_mul(&(output_end))
-            .expect("overflow of the selected intermediate type");
-        Self {
-            iter,
-            denominator: if max == zero::<U>() {
-                one::<U>()
-            } else {
-                max - min
-            },
-            numerator: output_end
-                .checked_sub(&output_start)
-                .expect("lower range bound must be less than upper"),
-            input_start: min,
-            output_start,
-            phantom: PhantomData,
-        }
+        max.checked_mul(&(output_end))
+            .ok_or(IterExtdError::Overflow)?;
+        let map = if max < min {
+            // Equal negative values of a signed intermediate type, the reversed input range
+            // `0..=max` maps them to the upper bound of the output range.
+            let mut map = ScaleMap::from_bounds(max, max, output_start, output_end)?;
+            map.output_start = output_end;
+            map
+        } else {
+            ScaleMap::from_bounds(min, max, output_start, output_end)?
+        };
+        Ok(Self { map, iter })
     }
 }
 
@@ -91,18 +94,14 @@ where
         + Mul<Output = U>
         + Div<Output = U>
         + Add<Output = U>
+        + PartialOrd
         + Copy,
     R: convert_by_add::FromByAddAll<U>,
 {
     type Item = R;
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(|val| {
-            R::from_by_add_all(
-                (U::from_by_add_all(val) - self.input_start) * self.numerator / self.denominator
-                    + self.output_start,
-            )
-        })
+        self.iter.next().map(|val| self.map.scale(val))
     }
 
     #[inline]
@@ -152,7 +151,7 @@ where
             + TryFromByAdd<R>
             + TryFromByAdd<Self::Item>
             + convert_by_add::FromByAddAll<Self::Item>
-            + PartialEq
+            + PartialOrd
             + CheckedSub
             + CheckedMul
             + One
@@ -162,61 +161,1803 @@ where
     {
         Scaling::new(self, output_range)
     }
-}
 
-impl<I, U, R> ExactSizeIterator for Scaling<I, U, R>
-where
-    I: ExactSizeIterator,
-    U: convert_by_add::FromByAddAll<I::Item>
-        + Sub<Output = U>
-        + Mul<Output = U>
-        + Div<Output = U>
-        + Add<Output = U>
-        + Copy,
-    R: convert_by_add::FromByAddAll<U>,
-{
     #[inline]
-    fn len(&self) -> usize {
-        self.iter.len()
+    /// Scale the values of the input iterator to the specified range, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`scaling`](Scaler::scaling).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::Overflow`] if the input or output value cannot be converted to the
+    /// selected intermediate type or when multiplying maximum input and output values overflows.
+    /// Returns [`IterExtdError::InvalidRange`] when the lower bound of the range is greater than the upper bound.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, Scaler};
+    ///
+    /// let res = [0u8, 255].into_iter().try_scaling::<u8>(..=255u8);
+    /// assert_eq!(res.err(), Some(IterExtdError::Overflow));
+    ///
+    /// let iter = (0..=5u8).try_scaling::<u16>(..70u8).unwrap();
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 14, 28, 42, 56, 70]);
+    /// ```
+    fn try_scaling<U>(
+        self,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> Result<Scaling<Self, U, R>, IterExtdError>
+    where
+        Self::Item: PartialOrd + Copy,
+        U: Sub<Output = U>
+            + TryFromByAdd<R>
+            + TryFromByAdd<Self::Item>
+            + convert_by_add::FromByAddAll<Self::Item>
+            + PartialOrd
+            + CheckedSub
+            + CheckedMul
+            + One
+            + Zero
+            + Copy,
+        Self: Sized + Clone,
+    {
+        Scaling::try_new(self, output_range)
     }
-}
-impl<I, U, R> FusedIterator for Scaling<I, U, R>
-where
-    I: FusedIterator,
-    U: convert_by_add::FromByAddAll<I::Item>
-        + Sub<Output = U>
-        + Mul<Output = U>
-        + Div<Output = U>
-        + Add<Output = U>
-        + Copy,
-    R: convert_by_add::FromByAddAll<U>,
-{
-}
 
-impl<I, U, R> DoubleEndedIterator for Scaling<I, U, R>
-where
-    I: DoubleEndedIterator,
-    U: convert_by_add::FromByAddAll<I::Item>
-        + Sub<Output = U>
-        + Mul<Output = U>
-        + Div<Output = U>
-        + Add<Output = U>
-        + Copy,
-    R: convert_by_add::FromByAddAll<U>,
-{
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter.next_back().map(|val| {
-            R::from_by_add_all(
-                (U::from_by_add_all(val) - self.input_start) * self.numerator / self.denominator
-                    + self.output_start,
-            )
-        })
+    /// Scale the values of the input iterator with a [`ScaleMap`].
+    ///
+    /// The values must be of the input type the map was created for, values out of its input
+    /// range are mapped to the nearest bound of the output range.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::Scaler;
+    ///
+    /// let map = (0..=4u8).scaling::<u16>(..=100u8).scale_map();
+    /// let iter = [1u8, 3].into_iter().scaling_with(map);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![25, 75]);
+    /// ```
+    fn scaling_with<U>(self, map: ScaleMap<U, R>) -> Scaling<Self, U, R>
+    where
+        Self: Sized,
+    {
+        Scaling { map, iter: self }
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator to the specified range without choosing an
+    /// intermediate type.
+    ///
+    /// Same as [`scaling`](Scaler::scaling), but the values are computed in `u128` with a
+    /// 256-bit intermediate product, so any pair of integer types can be scaled without overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panic when the lower bound of the range is greater than the upper bound.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::Scaler;
+    ///
+    /// let iter = [0u8, 255].into_iter().scaling_auto(..=255u8);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 255]);
+    ///
+    /// let iter = [i128::MIN, 0, i128::MAX].into_iter().scaling_auto(..);
+    /// assert_eq!(iter.collect::<Vec<u128>>(), vec![0, u128::MAX / 2 + 1, u128::MAX]);
+    /// ```
+    fn scaling_auto(
+        self,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> ScalingAuto<Self, R>
+    where
+        Self::Item: PartialOrd + Copy,
+        u128: TryFromByAdd<R> + TryFromByAdd<Self::Item> + convert_by_add::FromByAddAll<Self::Item>,
+        Self: Sized + Clone,
+    {
+        ScalingAuto::new(self, output_range)
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator to the specified range without choosing an
+    /// intermediate type, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`scaling_auto`](Scaler::scaling_auto).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::InvalidRange`] when the lower bound of the range is greater than the upper bound.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, Scaler};
+    ///
+    /// let res = [0u64].into_iter().try_scaling_auto((10, 5u8));
+    /// assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+    /// ```
+    fn try_scaling_auto(
+        self,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> Result<ScalingAuto<Self, R>, IterExtdError>
+    where
+        Self::Item: PartialOrd + Copy,
+        u128: TryFromByAdd<R> + TryFromByAdd<Self::Item> + convert_by_add::FromByAddAll<Self::Item>,
+        Self: Sized + Clone,
+    {
+        ScalingAuto::try_new(self, output_range)
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator from the specified input range to the output range.
+    ///
+    /// Unlike [`scaling`](Scaler::scaling), the input range is not searched for, so the iterator
+    /// is consumed in a single pass and does not need to be `Clone`. Values out of the input
+    /// range are clamped to the bounds of the output range.
+    ///
+    /// # Panics
+    ///
+    /// If the input or output bounds cannot be converted to the selected intermediate type.
+    /// Panic when the lower bound of a range is greater than the upper bound.
+    /// Overflow when multiplying the widths of the input and output ranges.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::Scaler;
+    ///
+    /// let adc = [0u16, 2048, 4095, 5000].into_iter();
+    /// let iter = adc.scaling_from::<u32>(..=4095u16, ..=255u8);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 127, 255, 255]);
+    /// ```
+    fn scaling_from<U>(
+        self,
+        input_range: impl convert_by_add::RangeBoundsInner<Self::Item>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> ScalingFrom<Self, U, R>
+    where
+        Self::Item: Bounded + Copy,
+        U: TryFromByAdd<R>
+            + TryFromByAdd<Self::Item>
+            + CheckedSub
+            + CheckedMul
+            + PartialOrd
+            + One
+            + Zero
+            + Copy,
+        Self: Sized,
+    {
+        ScalingFrom::new(self, input_range, output_range)
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator from the specified input range to the output range,
+    /// returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`scaling_from`](Scaler::scaling_from).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::Overflow`] if the input or output bounds cannot be converted to
+    /// the selected intermediate type or when multiplying the widths of the ranges overflows.
+    /// Returns [`IterExtdError::InvalidRange`] when the lower bound of a range is greater than the upper bound.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, Scaler};
+    ///
+    /// let res = [0u8].into_iter().try_scaling_from::<u16>((10, 5), ..=255u8);
+    /// assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+    ///
+    /// let res = [0u8].into_iter().try_scaling_from::<u8>(.., ..=255u8);
+    /// assert_eq!(res.err(), Some(IterExtdError::Overflow));
+    /// ```
+    fn try_scaling_from<U>(
+        self,
+        input_range: impl convert_by_add::RangeBoundsInner<Self::Item>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> Result<ScalingFrom<Self, U, R>, IterExtdError>
+    where
+        Self::Item: Bounded + Copy,
+        U: TryFromByAdd<R>
+            + TryFromByAdd<Self::Item>
+            + CheckedSub
+            + CheckedMul
+            + PartialOrd
+            + One
+            + Zero
+            + Copy,
+        Self: Sized,
+    {
+        ScalingFrom::try_new(self, input_range, output_range)
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator from the specified input range to the output range,
+    /// yielding an error for values out of the input range.
+    ///
+    /// Same as [`scaling_from`](Scaler::scaling_from), but values out of the input range are
+    /// yielded as [`IterExtdError::OutOfBounds`] instead of being clamped.
+    ///
+    /// # Panics
+    ///
+    /// If the input or output bounds cannot be converted to the selected intermediate type.
+    /// Panic when the lower bound of a range is greater than the upper bound.
+    /// Overflow when multiplying the widths of the input and output ranges.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, Scaler};
+    ///
+    /// let iter = [-1i16, 0, 100].into_iter().scaling_from_checked::<u32>(0..=100i16, 0..=10u8);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![Err(IterExtdError::OutOfBounds), Ok(0), Ok(10)]);
+    /// ```
+    fn scaling_from_checked<U>(
+        self,
+        input_range: impl convert_by_add::RangeBoundsInner<Self::Item>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> ScalingFromChecked<Self, U, R>
+    where
+        Self::Item: Bounded + Copy,
+        U: TryFromByAdd<R>
+            + TryFromByAdd<Self::Item>
+            + CheckedSub
+            + CheckedMul
+            + PartialOrd
+            + One
+            + Zero
+            + Copy,
+        Self: Sized,
+    {
+        ScalingFromChecked {
+            inner: ScalingFrom::new(self, input_range, output_range),
+        }
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator to the specified range, with float inputs or outputs.
+    ///
+    /// The values are mapped in `f64`. Integer outputs are rounded with `rounding`, `None`
+    /// truncates them towards the lower bound of the output range. Float outputs are not rounded
+    /// and `rounding` is ignored. NaN and infinite input values are handled according to
+    /// `non_finite` and do not take part in finding the input range. If all values are equal,
+    /// they are mapped to the lower bound of the output range.
+    ///
+    /// Integers with a magnitude above 2<sup>53</sup> are not exactly representable in `f64` and
+    /// lose precision, use [`scaling`](Scaler::scaling) or [`scaling_from`](Scaler::scaling_from)
+    /// for exact integer to integer scaling.
+    ///
+    /// # Panics
+    ///
+    /// Panic when the lower bound of the range is greater than the upper bound.
+    /// Panic on NaN or infinite values with [`NonFinite::Panic`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{NonFinite, Rounding, Scaler};
+    ///
+    /// let arr = [0.0, 0.25, f64::NAN, 1.0];
+    /// let iter = arr.into_iter().scaling_float(..=u8::MAX, Some(Rounding::Nearest), NonFinite::Skip);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 64, 255]);
+    ///
+    /// let iter = [0u16, 2048, 4096].into_iter().scaling_float(0.0..=3.3f32, None, NonFinite::Skip);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0.0, 1.65, 3.3]);
+    /// ```
+    fn scaling_float(
+        self,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> ScalingFloat<Self, R>
+    where
+        Self::Item: NumCast,
+        R: NumCast,
+        Self: Sized + Clone,
+    {
+        ScalingFloat::new(self, output_range, rounding, non_finite)
     }
+
+    #[inline]
+    /// Scale the values of the input iterator to the specified range, with float inputs or outputs,
+    /// returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`scaling_float`](Scaler::scaling_float).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::InvalidRange`] when the lower bound of the range is greater than
+    /// the upper bound or a bound is NaN.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, NonFinite, Scaler};
+    ///
+    /// let res = [0.5f32].into_iter().try_scaling_float(1.0..=0.0f64, None, NonFinite::Skip);
+    /// assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+    /// ```
+    fn try_scaling_float(
+        self,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> Result<ScalingFloat<Self, R>, IterExtdError>
+    where
+        Self::Item: NumCast,
+        R: NumCast,
+        Self: Sized + Clone,
+    {
+        ScalingFloat::try_new(self, output_range, rounding, non_finite)
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator from the specified input range to the output range
+    /// on a logarithmic scale.
+    ///
+    /// The logarithms of the values are scaled linearly, like a level meter in decibels.
+    /// Values out of the input range are clamped to the bounds of the output range. Zero and
+    /// negative values have no logarithm and are handled like NaN and infinite values according
+    /// to `non_finite`, zero maps to the lower bound with [`NonFinite::Clamp`]. Like
+    /// [`scaling_from`](Scaler::scaling_from), the iterator is consumed in a single pass and
+    /// does not need to be `Clone`. Otherwise the same as [`scaling_float`](Scaler::scaling_float).
+    ///
+    /// # Panics
+    ///
+    /// Panic when the lower bound of a range is greater than the upper bound or the lower bound
+    /// of the input range is not positive.
+    /// Panic on values without a finite logarithm with [`NonFinite::Panic`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{NonFinite, Rounding, Scaler};
+    ///
+    /// let levels = [0u32, 1, 10, 100, 1000, 5000];
+    /// let iter = levels.into_iter().scaling_log(1..=1000, 0..=30u8, Some(Rounding::Nearest), NonFinite::Clamp);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 0, 10, 20, 30, 30]);
+    /// ```
+    fn scaling_log<'a>(
+        self,
+        input_range: impl convert_by_add::RangeBoundsInner<Self::Item>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> ScalingCurve<'a, Self, R>
+    where
+        Self::Item: Bounded + NumCast + Copy,
+        R: NumCast,
+        Self: Sized,
+    {
+        self.try_scaling_log(input_range, output_range, rounding, non_finite)
+            .unwrap_or_else(|_| {
+                panic!("the lower bound of the input range must be positive and less than upper")
+            })
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator from the specified input range to the output range
+    /// on a logarithmic scale, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`scaling_log`](Scaler::scaling_log).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::InvalidRange`] when the lower bound of a range is greater than
+    /// the upper bound or the lower bound of the input range is not positive.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, NonFinite, Scaler};
+    ///
+    /// let res = [1.0, 10.0].into_iter().try_scaling_log(0.0..=10.0, 0.0..=1.0f64, None, NonFinite::Skip);
+    /// assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+    /// ```
+    fn try_scaling_log<'a>(
+        self,
+        input_range: impl convert_by_add::RangeBoundsInner<Self::Item>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> Result<ScalingCurve<'a, Self, R>, IterExtdError>
+    where
+        Self::Item: Bounded + NumCast + Copy,
+        R: NumCast,
+        Self: Sized,
+    {
+        let (start, end) = bounds_f64(input_range).ok_or(IterExtdError::InvalidRange)?;
+        if start.is_nan() || start <= 0.0 {
+            return Err(IterExtdError::InvalidRange);
+        }
+        ScalingCurve::try_new(
+            self,
+            Curve::Log,
+            (Float::ln(start), Float::ln(end)),
+            output_range,
+            rounding,
+            non_finite,
+        )
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator to the specified range with a gamma curve.
+    ///
+    /// The position of each value within the input range, from `0.0` to `1.0`, is raised to the
+    /// power of `gamma` before it is mapped to the output range. Otherwise the same as
+    /// [`scaling_float`](Scaler::scaling_float).
+    ///
+    /// # Panics
+    ///
+    /// Panic when the lower bound of the range is greater than the upper bound.
+    /// Panic if `gamma` is not a positive finite number.
+    /// Panic on NaN or infinite values with [`NonFinite::Panic`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{NonFinite, Rounding, Scaler};
+    ///
+    /// let iter = [0u8, 64, 128, 255].into_iter().scaling_gamma(..=255u8, 2.2, Some(Rounding::Nearest), NonFinite::Skip);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 12, 56, 255]);
+    /// ```
+    fn scaling_gamma<'a>(
+        self,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        gamma: f64,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> ScalingCurve<'a, Self, R>
+    where
+        Self::Item: NumCast,
+        R: NumCast,
+        Self: Sized + Clone,
+    {
+        assert!(
+            gamma > 0.0 && gamma.is_finite(),
+            "the gamma must be a positive finite number"
+        );
+        self.try_scaling_gamma(output_range, gamma, rounding, non_finite)
+            .unwrap_or_else(|_| panic!("lower range bound must be less than upper"))
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator to the specified range with a gamma curve,
+    /// returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`scaling_gamma`](Scaler::scaling_gamma).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::InvalidCurve`] if `gamma` is not a positive finite number.
+    /// Returns [`IterExtdError::InvalidRange`] when the lower bound of the range is greater than
+    /// the upper bound.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, NonFinite, Scaler};
+    ///
+    /// let res = [0u8, 255].into_iter().try_scaling_gamma(..=255u8, -1.0, None, NonFinite::Skip);
+    /// assert_eq!(res.err(), Some(IterExtdError::InvalidCurve));
+    /// ```
+    fn try_scaling_gamma<'a>(
+        self,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        gamma: f64,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> Result<ScalingCurve<'a, Self, R>, IterExtdError>
+    where
+        Self::Item: NumCast,
+        R: NumCast,
+        Self: Sized + Clone,
+    {
+        if !(gamma > 0.0 && gamma.is_finite()) {
+            return Err(IterExtdError::InvalidCurve);
+        }
+        let input_bounds = finite_bounds(self.clone());
+        ScalingCurve::try_new(
+            self,
+            Curve::Gamma(gamma),
+            input_bounds,
+            output_range,
+            rounding,
+            non_finite,
+        )
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator from the specified input range to the output range
+    /// with a piecewise-linear curve.
+    ///
+    /// The curve is given by points `(input, output)` sorted by `input`, both relative positions
+    /// where `0.0` is the lower and `1.0` the upper bound of the input and output ranges.
+    /// The position of each value is interpolated linearly between the neighbouring points,
+    /// positions before the first or after the last point take the output of that point.
+    /// Like [`scaling_from`](Scaler::scaling_from), the iterator is consumed in a single pass and
+    /// does not need to be `Clone`. Otherwise the same as [`scaling_float`](Scaler::scaling_float).
+    ///
+    /// # Panics
+    ///
+    /// Panic when the lower bound of a range is greater than the upper bound.
+    /// Panic if there are no points or the points are not finite and sorted by input.
+    /// Panic on NaN or infinite values with [`NonFinite::Panic`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{NonFinite, Rounding, Scaler};
+    ///
+    /// let curve = [(0.0, 0.0), (0.5, 0.8), (1.0, 1.0)];
+    /// let iter = (2..=4u8).scaling_piecewise(&curve, 0..=4, 0..=100u8, Some(Rounding::Nearest), NonFinite::Skip);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![80, 90, 100]);
+    /// ```
+    fn scaling_piecewise(
+        self,
+        points: &[(f64, f64)],
+        input_range: impl convert_by_add::RangeBoundsInner<Self::Item>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> ScalingCurve<'_, Self, R>
+    where
+        Self::Item: Bounded + NumCast + Copy,
+        R: NumCast,
+        Self: Sized,
+    {
+        assert!(
+            valid_points(points),
+            "the points must be finite and sorted by input"
+        );
+        self.try_scaling_piecewise(points, input_range, output_range, rounding, non_finite)
+            .unwrap_or_else(|_| panic!("lower range bound must be less than upper"))
+    }
+
+    #[inline]
+    /// Scale the values of the input iterator from the specified input range to the output range
+    /// with a piecewise-linear curve, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`scaling_piecewise`](Scaler::scaling_piecewise).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::InvalidCurve`] if there are no points or the points are not
+    /// finite and sorted by input.
+    /// Returns [`IterExtdError::InvalidRange`] when the lower bound of a range is greater than
+    /// the upper bound.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, NonFinite, Scaler};
+    ///
+    /// let curve = [(0.5, 0.0), (0.0, 1.0)];
+    /// let res = (0..=4u8).try_scaling_piecewise(&curve, .., 0..=100u8, None, NonFinite::Skip);
+    /// assert_eq!(res.err(), Some(IterExtdError::InvalidCurve));
+    /// ```
+    fn try_scaling_piecewise(
+        self,
+        points: &[(f64, f64)],
+        input_range: impl convert_by_add::RangeBoundsInner<Self::Item>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> Result<ScalingCurve<'_, Self, R>, IterExtdError>
+    where
+        Self::Item: Bounded + NumCast + Copy,
+        R: NumCast,
+        Self: Sized,
+    {
+        if !valid_points(points) {
+            return Err(IterExtdError::InvalidCurve);
+        }
+        let input_bounds = bounds_f64(input_range).ok_or(IterExtdError::InvalidRange)?;
+        ScalingCurve::try_new(
+            self,
+            Curve::Piecewise(points),
+            input_bounds,
+            output_range,
+            rounding,
+            non_finite,
+        )
+    }
+}
+
+impl<I, U, R> ExactSizeIterator for Scaling<I, U, R>
+where
+    I: ExactSizeIterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+impl<I, U, R> FusedIterator for Scaling<I, U, R>
+where
+    I: FusedIterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+}
+
+impl<I, U, R> DoubleEndedIterator for Scaling<I, U, R>
+where
+    I: DoubleEndedIterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|val| self.map.scale(val))
+    }
+}
+
+/// Structure that stores data, parameters for the [`scaling_auto`](Scaler::scaling_auto)
+/// iterator adapter.
+#[derive(Debug, Clone)]
+pub struct ScalingAuto<I, R> {
+    denominator: u128,
+    numerator: u128,
+    input_start: u128,
+    output_start: u128,
+    iter: I,
+    phantom: PhantomData<R>,
+}
+
+impl<I, R> ScalingAuto<I, R>
+where
+    I: Iterator + Clone,
+    I::Item: PartialOrd + Copy,
+    u128: TryFromByAdd<R> + TryFromByAdd<I::Item> + convert_by_add::FromByAddAll<I::Item>,
+    R: Bounded + Copy,
+{
+    #[inline]
+    pub(crate) fn new(iter: I, output_range: impl convert_by_add::RangeBoundsInner<R>) -> Self {
+        Self::try_new(iter, output_range)
+            .unwrap_or_else(|_| panic!("lower range bound must be less than upper"))
+    }
+
+    #[inline]
+    pub(crate) fn try_new(
+        iter: I,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> Result<Self, IterExtdError> {
+        let (output_start, output_end) = bounds_by_add::<R, u128>(output_range)?;
+
+        let (min, max) = match iter.clone().minmax() {
+            NoElements => (0, 0),
+            OneElement(max) => (
+                0,
+                <u128 as convert_by_add::FromByAddAll<_>>::from_by_add_all(max),
+            ),
+            MinMax(min, max) if min == max => (
+                0,
+                <u128 as convert_by_add::FromByAddAll<_>>::from_by_add_all(max),
+            ),
+            MinMax(min, max) => (
+                <u128 as convert_by_add::FromByAddAll<_>>::from_by_add_all(min),
+                <u128 as convert_by_add::FromByAddAll<_>>::from_by_add_all(max),
+            ),
+        };
+
+        Ok(Self {
+            iter,
+            denominator: if max == 0 { 1 } else { max - min },
+            numerator: output_end
+                .checked_sub(output_start)
+                .ok_or(IterExtdError::InvalidRange)?,
+            input_start: min,
+            output_start,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<I, R> ScalingAuto<I, R>
+where
+    I: Iterator,
+    u128: convert_by_add::FromByAddAll<I::Item>,
+    R: convert_by_add::FromByAddAll<u128>,
+{
+    #[inline]
+    fn scale(&self, val: I::Item) -> R {
+        let value =
+            <u128 as convert_by_add::FromByAddAll<_>>::from_by_add_all(val) - self.input_start;
+        R::from_by_add_all(mul_div(value, self.numerator, self.denominator) + self.output_start)
+    }
+}
+
+/// Computes `a * b / c` without intermediate overflow, the quotient must fit into `u128`.
+#[inline]
+pub(crate) fn mul_div(a: u128, b: u128, c: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product / c;
+    }
+
+    // Multiplies into a 256-bit product of 64-bit limbs.
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+    let lo_lo = a_lo * b_lo;
+    let mid = (lo_lo >> 64) + ((a_hi * b_lo) & MASK) + ((a_lo * b_hi) & MASK);
+    let lo = (lo_lo & MASK) | (mid << 64);
+    let hi = a_hi * b_hi + ((a_hi * b_lo) >> 64) + ((a_lo * b_hi) >> 64) + (mid >> 64);
+
+    // Long division of the lower half, the upper half is less than `c`.
+    let mut rem = hi;
+    let mut quot = 0;
+    for bit in (0..128).rev() {
+        let carry = rem >> 127;
+        rem = (rem << 1) | ((lo >> bit) & 1);
+        quot <<= 1;
+        if carry == 1 || rem >= c {
+            rem = rem.wrapping_sub(c);
+            quot |= 1;
+        }
+    }
+
+    quot
+}
+
+impl<I, R> Iterator for ScalingAuto<I, R>
+where
+    I: Iterator,
+    u128: convert_by_add::FromByAddAll<I::Item>,
+    R: convert_by_add::FromByAddAll<u128>,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|val| self.scale(val))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, R> DoubleEndedIterator for ScalingAuto<I, R>
+where
+    I: DoubleEndedIterator,
+    u128: convert_by_add::FromByAddAll<I::Item>,
+    R: convert_by_add::FromByAddAll<u128>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|val| self.scale(val))
+    }
+}
+
+impl<I, R> ExactSizeIterator for ScalingAuto<I, R>
+where
+    I: ExactSizeIterator,
+    u128: convert_by_add::FromByAddAll<I::Item>,
+    R: convert_by_add::FromByAddAll<u128>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, R> FusedIterator for ScalingAuto<I, R>
+where
+    I: FusedIterator,
+    u128: convert_by_add::FromByAddAll<I::Item>,
+    R: convert_by_add::FromByAddAll<u128>,
+{
+}
+
+/// A linear map of values from an input range to an output range, computed in the
+/// intermediate type `U`.
+///
+/// The map can be taken from a [`Scaling`] or [`ScalingFrom`] adapter with `scale_map`, or created
+/// from known ranges. It scales single values, other iterators with
+/// [`scaling_with`](Scaler::scaling_with), and can be inverted to map output values back to
+/// the input range. With the `serde` feature, the map is serialized as the bounds of its input
+/// and output ranges, which are validated on deserialization.
+///
+/// The map does not record the type of its input range. Values must be of the type the map was
+/// created for, and [`inverse`](ScaleMap::inverse) must be given that same type, values of
+/// other types are converted to `U` with a different offset and are silently mis-scaled.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iterextd::{ScaleMap, Scaler};
+///
+/// let map = ScaleMap::<u32, u8>::new(..=4095u16, ..=255u8);
+/// assert_eq!(map.scale(4095u16), 255);
+///
+/// let iter = [0u16, 2048].into_iter().scaling_with(map);
+/// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 127]);
+///
+/// assert_eq!(map.inverse::<u16>().scale(255u8), 4095);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "ScaleMapBounds<U>",
+        bound(
+            deserialize = "U: serde::Deserialize<'de> + CheckedMul + CheckedSub + PartialOrd + Copy"
+        )
+    )
+)]
+pub struct ScaleMap<U, R> {
+    input_start: U,
+    input_end: U,
+    output_start: U,
+    output_end: U,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    phantom: PhantomData<R>,
+}
+
+/// The bounds of a serialized [`ScaleMap`], validated when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ScaleMapBounds<U> {
+    input_start: U,
+    input_end: U,
+    output_start: U,
+    output_end: U,
+}
+
+#[cfg(feature = "serde")]
+impl<U, R> TryFrom<ScaleMapBounds<U>> for ScaleMap<U, R>
+where
+    U: CheckedMul + CheckedSub + PartialOrd + Copy,
+{
+    type Error = IterExtdError;
+
+    #[inline]
+    fn try_from(bounds: ScaleMapBounds<U>) -> Result<Self, Self::Error> {
+        Self::from_bounds(
+            bounds.input_start,
+            bounds.input_end,
+            bounds.output_start,
+            bounds.output_end,
+        )
+    }
+}
+
+impl<U: Clone, R> Clone for ScaleMap<U, R> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            input_start: self.input_start.clone(),
+            input_end: self.input_end.clone(),
+            output_start: self.output_start.clone(),
+            output_end: self.output_end.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<U: Copy, R> Copy for ScaleMap<U, R> {}
+
+impl<U, R> ScaleMap<U, R>
+where
+    U: TryFromByAdd<R> + CheckedMul + CheckedSub + PartialOrd + Copy,
+    R: Bounded + Copy,
+{
+    /// Creates a map from the input range to the output range.
+    ///
+    /// # Panics
+    ///
+    /// If the input or output bounds cannot be converted to the selected intermediate type.
+    /// Panic when the lower bound of a range is greater than the upper bound.
+    /// Overflow when multiplying the widths of the input and output ranges.
+    #[inline]
+    pub fn new<T>(
+        input_range: impl convert_by_add::RangeBoundsInner<T>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> Self
+    where
+        T: Bounded + Copy,
+        U: TryFromByAdd<T>,
+    {
+        Self::try_new(input_range, output_range).unwrap_or_else(|err| match err {
+            IterExtdError::InvalidRange => panic!("lower range bound must be less than upper"),
+            _ => panic!("overflow of the selected intermediate type"),
+        })
+    }
+
+    /// Creates a map from the input range to the output range, returning an error instead of
+    /// panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::Overflow`] if the input or output bounds cannot be converted to
+    /// the selected intermediate type or when multiplying the widths of the ranges overflows.
+    /// Returns [`IterExtdError::InvalidRange`] when the lower bound of a range is greater than the upper bound.
+    #[inline]
+    pub fn try_new<T>(
+        input_range: impl convert_by_add::RangeBoundsInner<T>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> Result<Self, IterExtdError>
+    where
+        T: Bounded + Copy,
+        U: TryFromByAdd<T>,
+    {
+        let (input_start, input_end) = bounds_by_add::<T, U>(input_range)?;
+        let (output_start, output_end) = bounds_by_add::<R, U>(output_range)?;
+        Self::from_bounds(input_start, input_end, output_start, output_end)
+    }
+}
+
+impl<U, R> ScaleMap<U, R>
+where
+    U: CheckedMul + CheckedSub + PartialOrd + Copy,
+{
+    #[inline]
+    fn from_bounds(
+        input_start: U,
+        input_end: U,
+        output_start: U,
+        output_end: U,
+    ) -> Result<Self, IterExtdError> {
+        if input_start > input_end || output_start > output_end {
+            return Err(IterExtdError::InvalidRange);
+        }
+        let input_width = input_end
+            .checked_sub(&input_start)
+            .ok_or(IterExtdError::Overflow)?;
+        let output_width = output_end
+            .checked_sub(&output_start)
+            .ok_or(IterExtdError::Overflow)?;
+        input_width
+            .checked_mul(&output_width)
+            .ok_or(IterExtdError::Overflow)?;
+        Ok(Self {
+            input_start,
+            input_end,
+            output_start,
+            output_end,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<U: Copy, R> ScaleMap<U, R> {
+    /// Returns the inverse map, from the output range back to the input range of type `T`.
+    ///
+    /// Scaling a value there and back is exact only if no rounding occurs. If the input range
+    /// holds a single value, the inverse maps every value to it.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::ScaleMap;
+    ///
+    /// let map = ScaleMap::<u16, i8>::new(0..=200u8, -100..=100i8);
+    /// assert_eq!(map.scale(150u8), 50);
+    /// assert_eq!(map.inverse::<u8>().scale(50i8), 150);
+    /// ```
+    #[inline]
+    pub fn inverse<T>(&self) -> ScaleMap<U, T> {
+        ScaleMap {
+            input_start: self.output_start,
+            input_end: self.output_end,
+            output_start: self.input_start,
+            output_end: self.input_end,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<U, R> ScaleMap<U, R>
+where
+    U: Sub<Output = U> + Mul<Output = U> + Div<Output = U> + Add<Output = U> + PartialOrd + Copy,
+{
+    /// Scales a single value of the input range.
+    ///
+    /// The value must be of the input type the map was created for. Values out of the input
+    /// range are mapped to the nearest bound of the output range, and if the input range holds
+    /// a single value, it is mapped to the lower bound of the output range.
+    #[inline]
+    pub fn scale<T>(&self, value: T) -> R
+    where
+        U: convert_by_add::FromByAddAll<T>,
+        R: convert_by_add::FromByAddAll<U>,
+    {
+        R::from_by_add_all(
+            self.scale_inner(U::from_by_add_all(value))
+                .unwrap_or_else(|clamped| clamped),
+        )
+    }
+
+    /// Scales a single value of the input range, returning an error if it is out of the range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::OutOfBounds`] if the value is out of the input range.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, ScaleMap};
+    ///
+    /// let map = ScaleMap::<u16, u8>::new(100..=200u8, 0..=100u8);
+    /// assert_eq!(map.checked_scale(150u8), Ok(50));
+    /// assert_eq!(map.checked_scale(50u8), Err(IterExtdError::OutOfBounds));
+    /// ```
+    #[inline]
+    pub fn checked_scale<T>(&self, value: T) -> Result<R, IterExtdError>
+    where
+        U: convert_by_add::FromByAddAll<T>,
+        R: convert_by_add::FromByAddAll<U>,
+    {
+        self.scale_inner(U::from_by_add_all(value))
+            .map(R::from_by_add_all)
+            .map_err(|_| IterExtdError::OutOfBounds)
+    }
+
+    /// Scales a value, the bound of the output range if the value is out of the input range.
+    #[inline]
+    fn scale_inner(&self, value: U) -> Result<U, U> {
+        if value < self.input_start {
+            Err(self.output_start)
+        } else if value > self.input_end {
+            Err(self.output_end)
+        } else if self.input_start == self.input_end {
+            Ok(self.output_start)
+        } else {
+            Ok(
+                (value - self.input_start) * (self.output_end - self.output_start)
+                    / (self.input_end - self.input_start)
+                    + self.output_start,
+            )
+        }
+    }
+}
+
+impl<I: Iterator, U: Copy, R> Scaling<I, U, R> {
+    /// Returns the map that the adapter applies to the values.
+    #[inline]
+    pub fn scale_map(&self) -> ScaleMap<U, R> {
+        self.map
+    }
+}
+
+/// Structure that stores data, parameters for the [`scaling_from`](Scaler::scaling_from)
+/// iterator adapter.
+#[derive(Debug, Clone)]
+pub struct ScalingFrom<I, U, R> {
+    map: ScaleMap<U, R>,
+    iter: I,
+}
+
+impl<I, U, R> ScalingFrom<I, U, R>
+where
+    I: Iterator,
+    I::Item: Bounded + Copy,
+    U: TryFromByAdd<R>
+        + TryFromByAdd<I::Item>
+        + CheckedMul
+        + CheckedSub
+        + PartialOrd
+        + One
+        + Zero
+        + Copy,
+    R: Bounded + Copy,
+{
+    #[inline]
+    pub(crate) fn new(
+        iter: I,
+        input_range: impl convert_by_add::RangeBoundsInner<I::Item>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> Self {
+        Self::try_new(iter, input_range, output_range).unwrap_or_else(|err| match err {
+            IterExtdError::InvalidRange => panic!("lower range bound must be less than upper"),
+            _ => panic!("overflow of the selected intermediate type"),
+        })
+    }
+
+    #[inline]
+    pub(crate) fn try_new(
+        iter: I,
+        input_range: impl convert_by_add::RangeBoundsInner<I::Item>,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+    ) -> Result<Self, IterExtdError> {
+        let (input_start, input_end) = bounds_by_add::<I::Item, U>(input_range)?;
+        let (output_start, output_end) = bounds_by_add::<R, U>(output_range)?;
+        Ok(Self {
+            map: ScaleMap::from_bounds(input_start, input_end, output_start, output_end)?,
+            iter,
+        })
+    }
+}
+
+impl<I, U: Copy, R> ScalingFrom<I, U, R> {
+    /// Returns the map that the adapter applies to the values of the input range.
+    #[inline]
+    pub fn scale_map(&self) -> ScaleMap<U, R> {
+        self.map
+    }
+}
+
+/// Converts the bounds of a range to the intermediate type.
+#[inline]
+fn bounds_by_add<T, U>(
+    range: impl convert_by_add::RangeBoundsInner<T>,
+) -> Result<(U, U), IterExtdError>
+where
+    T: Bounded + Copy,
+    U: TryFromByAdd<T>,
+{
+    let start = U::try_from_by_add(match range.start_bound() {
+        convert_by_add::BoundInner::Included(&start) => start,
+        convert_by_add::BoundInner::Unbounded => <T>::min_value(),
+    })
+    .ok_or(IterExtdError::Overflow)?;
+    let end = U::try_from_by_add(match range.end_bound() {
+        convert_by_add::BoundInner::Included(&end) => end,
+        convert_by_add::BoundInner::Unbounded => <T>::max_value(),
+    })
+    .ok_or(IterExtdError::Overflow)?;
+
+    Ok((start, end))
+}
+
+impl<I, U, R> ScalingFrom<I, U, R>
+where
+    I: Iterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+    /// Scales a value, the bound of the output range if the value is out of the input range.
+    #[inline]
+    fn scale(&self, val: I::Item) -> Result<R, R> {
+        self.map
+            .scale_inner(U::from_by_add_all(val))
+            .map(R::from_by_add_all)
+            .map_err(R::from_by_add_all)
+    }
+}
+
+impl<I, U, R> Iterator for ScalingFrom<I, U, R>
+where
+    I: Iterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|val| self.scale(val).unwrap_or_else(|clamped| clamped))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, U, R> DoubleEndedIterator for ScalingFrom<I, U, R>
+where
+    I: DoubleEndedIterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|val| self.scale(val).unwrap_or_else(|clamped| clamped))
+    }
+}
+
+impl<I, U, R> ExactSizeIterator for ScalingFrom<I, U, R>
+where
+    I: ExactSizeIterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, U, R> FusedIterator for ScalingFrom<I, U, R>
+where
+    I: FusedIterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+}
+
+/// Structure that stores data, parameters for the
+/// [`scaling_from_checked`](Scaler::scaling_from_checked) iterator adapter.
+#[derive(Debug, Clone)]
+pub struct ScalingFromChecked<I, U, R> {
+    inner: ScalingFrom<I, U, R>,
+}
+
+impl<I, U, R> Iterator for ScalingFromChecked<I, U, R>
+where
+    I: Iterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+    type Item = Result<R, IterExtdError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let inner = &mut self.inner;
+        inner
+            .iter
+            .next()
+            .map(|val| inner.scale(val).map_err(|_| IterExtdError::OutOfBounds))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, U, R> DoubleEndedIterator for ScalingFromChecked<I, U, R>
+where
+    I: DoubleEndedIterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let inner = &mut self.inner;
+        inner
+            .iter
+            .next_back()
+            .map(|val| inner.scale(val).map_err(|_| IterExtdError::OutOfBounds))
+    }
+}
+
+impl<I, U, R> ExactSizeIterator for ScalingFromChecked<I, U, R>
+where
+    I: ExactSizeIterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<I, U, R> FusedIterator for ScalingFromChecked<I, U, R>
+where
+    I: FusedIterator,
+    U: convert_by_add::FromByAddAll<I::Item>
+        + Sub<Output = U>
+        + Mul<Output = U>
+        + Div<Output = U>
+        + Add<Output = U>
+        + PartialOrd
+        + Copy,
+    R: convert_by_add::FromByAddAll<U>,
+{
+}
+
+/// Policies for NaN and infinite input values of the [`scaling_float`](Scaler::scaling_float)
+/// iterator adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NonFinite {
+    /// Skips NaN and infinite values.
+    Skip,
+    /// Maps infinities to the nearest bound of the output range and skips NaN values.
+    Clamp,
+    /// Panics on NaN and infinite values.
+    Panic,
+}
+
+/// Structure that stores data, parameters for the [`scaling_float`](Scaler::scaling_float)
+/// iterator adapter.
+#[derive(Debug, Clone)]
+pub struct ScalingFloat<I, R> {
+    input_start: f64,
+    input_span: f64,
+    output_start: R,
+    output_end: R,
+    rounding: Option<Rounding>,
+    non_finite: NonFinite,
+    iter: I,
+}
+
+impl<I, R> ScalingFloat<I, R>
+where
+    I: Iterator,
+    I::Item: NumCast,
+    R: Bounded + NumCast + Copy,
+{
+    #[inline]
+    pub(crate) fn new(
+        iter: I,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> Self
+    where
+        I: Clone,
+    {
+        Self::try_new(iter, output_range, rounding, non_finite)
+            .unwrap_or_else(|_| panic!("lower range bound must be less than upper"))
+    }
+
+    #[inline]
+    pub(crate) fn try_new(
+        iter: I,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> Result<Self, IterExtdError>
+    where
+        I: Clone,
+    {
+        let (min, max) = finite_bounds(iter.clone());
+        Self::with_input_range(iter, min, max, output_range, rounding, non_finite)
+    }
+
+    /// Creates the adapter for a known finite input range.
+    #[inline]
+    pub(crate) fn with_input_range(
+        iter: I,
+        input_start: f64,
+        input_end: f64,
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> Result<Self, IterExtdError> {
+        let output_start = match output_range.start_bound() {
+            convert_by_add::BoundInner::Included(&start) => start,
+            convert_by_add::BoundInner::Unbounded => <R>::min_value(),
+        };
+        let output_end = match output_range.end_bound() {
+            convert_by_add::BoundInner::Included(&end) => end,
+            convert_by_add::BoundInner::Unbounded => <R>::max_value(),
+        };
+        match (to_f64(output_start), to_f64(output_end)) {
+            (Some(start), Some(end)) if start <= end => {}
+            _ => return Err(IterExtdError::InvalidRange),
+        }
+        if !input_start.is_finite() || !input_end.is_finite() || input_start > input_end {
+            return Err(IterExtdError::InvalidRange);
+        }
+
+        // Integer types truncate the fractional part when cast from a float.
+        let is_integer = <R as NumCast>::from(0.5).and_then(to_f64) == Some(0.0);
+        Ok(Self {
+            // Halved bounds keep the span finite for the whole range of `f64`.
+            input_start: input_start / 2.0,
+            input_span: input_end / 2.0 - input_start / 2.0,
+            output_start,
+            output_end,
+            rounding: is_integer.then(|| rounding.unwrap_or(Rounding::Floor)),
+            non_finite,
+            iter,
+        })
+    }
+
+    /// Maps an input value, `None` if the value is skipped.
+    #[inline]
+    fn scale(&self, value: I::Item) -> Option<R> {
+        self.ratio(to_f64(value).unwrap_or(f64::NAN))
+            .map(|ratio| self.output(ratio))
+    }
+
+    /// Returns the position of a value within the input range, from `0.0` to `1.0`.
+    #[inline]
+    fn ratio(&self, value: f64) -> Option<f64> {
+        if !value.is_finite() {
+            match self.non_finite {
+                NonFinite::Skip => None,
+                NonFinite::Clamp if value.is_nan() => None,
+                NonFinite::Clamp if value > 0.0 => Some(1.0),
+                NonFinite::Clamp => Some(0.0),
+                NonFinite::Panic => panic!("the value to scale must be finite"),
+            }
+        } else if self.input_span > 0.0 {
+            Some(((value / 2.0 - self.input_start) / self.input_span).clamp(0.0, 1.0))
+        } else {
+            Some(0.0)
+        }
+    }
+
+    /// Returns the output value at a position within the output range.
+    #[inline]
+    fn output(&self, ratio: f64) -> R {
+        let (Some(start), Some(end)) = (to_f64(self.output_start), to_f64(self.output_end)) else {
+            return self.output_start;
+        };
+        let mut scaled = start * (1.0 - ratio) + end * ratio;
+        if let Some(rounding) = self.rounding {
+            scaled = round(scaled, rounding);
+        }
+
+        if scaled <= start {
+            self.output_start
+        } else if scaled >= end {
+            self.output_end
+        } else {
+            <R as NumCast>::from(scaled).unwrap_or(self.output_end)
+        }
+    }
+}
+
+/// Finds the smallest and largest finite values, zeros if there are none.
+#[inline]
+fn finite_bounds<I>(iter: I) -> (f64, f64)
+where
+    I: Iterator,
+    I::Item: NumCast,
+{
+    iter.filter_map(to_f64)
+        .filter(|value| value.is_finite())
+        .fold(None, |acc, value| match acc {
+            None => Some((value, value)),
+            Some((min, max)) => Some((value.min(min), value.max(max))),
+        })
+        .unwrap_or((0.0, 0.0))
+}
+
+/// Converts the bounds of a range to `f64`, `None` if a bound is not representable.
+#[inline]
+fn bounds_f64<T>(range: impl convert_by_add::RangeBoundsInner<T>) -> Option<(f64, f64)>
+where
+    T: Bounded + NumCast + Copy,
+{
+    let start = match range.start_bound() {
+        convert_by_add::BoundInner::Included(&start) => start,
+        convert_by_add::BoundInner::Unbounded => <T>::min_value(),
+    };
+    let end = match range.end_bound() {
+        convert_by_add::BoundInner::Included(&end) => end,
+        convert_by_add::BoundInner::Unbounded => <T>::max_value(),
+    };
+
+    Some((to_f64(start)?, to_f64(end)?))
+}
+
+/// Checks that the points of a piecewise-linear curve are finite and sorted by input.
+#[inline]
+fn valid_points(points: &[(f64, f64)]) -> bool {
+    !points.is_empty()
+        && points
+            .iter()
+            .all(|&(input, output)| input.is_finite() && output.is_finite())
+        && points.windows(2).all(|pair| pair[0].0 <= pair[1].0)
+}
+
+#[inline]
+fn to_f64<T: NumCast>(value: T) -> Option<f64> {
+    <f64 as NumCast>::from(value)
+}
+
+#[inline]
+fn round(value: f64, rounding: Rounding) -> f64 {
+    match rounding {
+        Rounding::Floor => Float::floor(value),
+        Rounding::Ceil => Float::ceil(value),
+        Rounding::Nearest => {
+            let floor = Float::floor(value);
+            if value - floor >= 0.5 {
+                floor + 1.0
+            } else {
+                floor
+            }
+        }
+    }
+}
+
+impl<I, R> Iterator for ScalingFloat<I, R>
+where
+    I: Iterator,
+    I::Item: NumCast,
+    R: Bounded + NumCast + Copy,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let value = self.iter.next()?;
+            if let Some(scaled) = self.scale(value) {
+                return Some(scaled);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        match self.non_finite {
+            NonFinite::Panic => (lower, upper),
+            NonFinite::Skip | NonFinite::Clamp => (0, upper),
+        }
+    }
+}
+
+impl<I, R> DoubleEndedIterator for ScalingFloat<I, R>
+where
+    I: DoubleEndedIterator,
+    I::Item: NumCast,
+    R: Bounded + NumCast + Copy,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let value = self.iter.next_back()?;
+            if let Some(scaled) = self.scale(value) {
+                return Some(scaled);
+            }
+        }
+    }
+}
+
+impl<I, R> FusedIterator for ScalingFloat<I, R>
+where
+    I: FusedIterator,
+    I::Item: NumCast,
+    R: Bounded + NumCast + Copy,
+{
+}
+
+/// Curves of the nonlinear scaling iterator adapters.
+#[derive(Debug, Clone, Copy)]
+enum Curve<'a> {
+    Log,
+    Gamma(f64),
+    Piecewise(&'a [(f64, f64)]),
+}
+
+impl Curve<'_> {
+    /// Maps a position within the input range to a position within the output range.
+    #[inline]
+    fn apply(&self, ratio: f64) -> f64 {
+        match *self {
+            Curve::Log => ratio,
+            Curve::Gamma(gamma) => Float::powf(ratio, gamma),
+            Curve::Piecewise(points) => {
+                let idx = points.partition_point(|&(x, _)| x <= ratio);
+                if idx == 0 {
+                    return points[0].1;
+                }
+                if idx == points.len() {
+                    return points[idx - 1].1;
+                }
+                let ((x0, y0), (x1, y1)) = (points[idx - 1], points[idx]);
+                y0 + (y1 - y0) * (ratio - x0) / (x1 - x0)
+            }
+        }
+    }
+}
+
+/// Structure that stores data, parameters for the [`scaling_log`](Scaler::scaling_log),
+/// [`scaling_gamma`](Scaler::scaling_gamma) and [`scaling_piecewise`](Scaler::scaling_piecewise)
+/// iterator adapters.
+#[derive(Debug, Clone)]
+pub struct ScalingCurve<'a, I, R> {
+    curve: Curve<'a>,
+    inner: ScalingFloat<I, R>,
+}
+
+impl<'a, I, R> ScalingCurve<'a, I, R>
+where
+    I: Iterator,
+    I::Item: NumCast,
+    R: Bounded + NumCast + Copy,
+{
+    /// Creates the adapter for a known finite input range, given in logarithms for
+    /// [`Curve::Log`].
+    #[inline]
+    fn try_new(
+        iter: I,
+        curve: Curve<'a>,
+        (input_start, input_end): (f64, f64),
+        output_range: impl convert_by_add::RangeBoundsInner<R>,
+        rounding: Option<Rounding>,
+        non_finite: NonFinite,
+    ) -> Result<Self, IterExtdError> {
+        let inner = ScalingFloat::with_input_range(
+            iter,
+            input_start,
+            input_end,
+            output_range,
+            rounding,
+            non_finite,
+        )?;
+
+        Ok(Self { curve, inner })
+    }
+}
+
+impl<I, R> ScalingCurve<'_, I, R>
+where
+    I: Iterator,
+    I::Item: NumCast,
+    R: Bounded + NumCast + Copy,
+{
+    #[inline]
+    fn scale(&self, value: I::Item) -> Option<R> {
+        let mut value = to_f64(value).unwrap_or(f64::NAN);
+        if let Curve::Log = self.curve {
+            value = Float::ln(value);
+        }
+
+        self.inner
+            .ratio(value)
+            .map(|ratio| self.inner.output(self.curve.apply(ratio)))
+    }
+}
+
+impl<I, R> Iterator for ScalingCurve<'_, I, R>
+where
+    I: Iterator,
+    I::Item: NumCast,
+    R: Bounded + NumCast + Copy,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let value = self.inner.iter.next()?;
+            if let Some(scaled) = self.scale(value) {
+                return Some(scaled);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, R> DoubleEndedIterator for ScalingCurve<'_, I, R>
+where
+    I: DoubleEndedIterator,
+    I::Item: NumCast,
+    R: Bounded + NumCast + Copy,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let value = self.inner.iter.next_back()?;
+            if let Some(scaled) = self.scale(value) {
+                return Some(scaled);
+            }
+        }
+    }
+}
+
+impl<I, R> FusedIterator for ScalingCurve<'_, I, R>
+where
+    I: FusedIterator,
+    I::Item: NumCast,
+    R: Bounded + NumCast + Copy,
+{
 }
 
 pub(crate) mod convert_by_add {
-    use std::ops::{RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+    use core::ops::{RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
     pub trait FromByAddAll<T> {
         fn from_by_add_all(n: T) -> Self
         where
@@ -1,7 +1,31 @@
-use crate::FusedIterator;
+use crate::fmt;
+use crate::IterExtdError;
+#[cfg(feature = "alloc")]
+use crate::Vec;
+use crate::{Fuse, FusedIterator};
 
 impl<T: ?Sized> Iter2D for T where T: Iterator {}
 
+impl<I> FusedIterator for Crop2D<I> where I: Iterator {}
+
+impl<I> ExactSizeIterator for Crop2D<I> where I: ExactSizeIterator {}
+
+#[cfg(feature = "alloc")]
+impl<I> FusedIterator for Remap2D<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<I> ExactSizeIterator for Remap2D<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
 impl<I, J, T> FusedIterator for Overlay2D<I, J, T>
 where
     I: FusedIterator<Item = T>,
@@ -25,6 +49,160 @@ where
 /// This trait enables working with abstract 2D data structures using regular 1D iterators.
 /// It is especially useful for overlaying or manipulating rectangular regions within a flat data buffer.
 pub trait Iter2D: Iterator {
+    /// Crops a rectangular region out of the base 2D area.
+    ///
+    /// # Arguments
+    /// * `source_size` - Size of the base 2D area as (width, height).
+    /// * `crop_size` - Size of the cropped region as (width, height).
+    /// * `crop_offset` - Offset (x, y) of the cropped region within the base area.
+    ///
+    /// # Panics
+    /// Panics if the cropped region does not fit within the base area or if any size is zero.
+    ///
+    /// # Returns
+    /// Returns a `Crop2D` iterator that yields the elements of the cropped region in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::Iter2D;
+    ///
+    /// let vec = (0..16).crop_2d((4, 4), (2, 3), (1, 1)).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![5, 6, 9, 10, 13, 14]);
+    /// ```
+    #[inline]
+    fn crop_2d(
+        self,
+        source_size: (usize, usize),
+        crop_size: (usize, usize),
+        crop_offset: (usize, usize),
+    ) -> Crop2D<Self>
+    where
+        Self: Sized,
+    {
+        self.try_crop_2d(source_size, crop_size, crop_offset)
+            .unwrap_or_else(|err| match err {
+                IterExtdError::ZeroSize => panic!("the size of abstract 2D data must not be zero"),
+                IterExtdError::Overflow => panic!("the size of abstract 2D data overflows usize"),
+                _ => {
+                    panic!("the offset and size of the cropped abstract 2D data exceed the bounds")
+                }
+            })
+    }
+
+    /// Crops a rectangular region out of the base 2D area, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`crop_2d`](Iter2D::crop_2d).
+    ///
+    /// # Errors
+    /// Returns [`IterExtdError::ZeroSize`] if any size is zero, [`IterExtdError::Overflow`]
+    /// if the base area overflows `usize` and [`IterExtdError::OutOfBounds`]
+    /// if the cropped region does not fit within the base area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Iter2D, IterExtdError};
+    ///
+    /// let res = (0..16).try_crop_2d((4, 4), (0, 2), (1, 1));
+    /// assert_eq!(res.err(), Some(IterExtdError::ZeroSize));
+    /// ```
+    #[inline]
+    fn try_crop_2d(
+        self,
+        source_size: (usize, usize),
+        crop_size: (usize, usize),
+        crop_offset: (usize, usize),
+    ) -> Result<Crop2D<Self>, IterExtdError>
+    where
+        Self: Sized,
+    {
+        if (source_size.0 == 0) || (source_size.1 == 0) || (crop_size.0 == 0) || (crop_size.1 == 0)
+        {
+            return Err(IterExtdError::ZeroSize);
+        }
+
+        area_2d(source_size)?;
+
+        if !fits_2d(crop_offset.0, crop_size.0, source_size.0)
+            || !fits_2d(crop_offset.1, crop_size.1, source_size.1)
+        {
+            return Err(IterExtdError::OutOfBounds);
+        }
+
+        Ok(Crop2D {
+            iter: self,
+            source_width: source_size.0,
+            crop_width: crop_size.0,
+            column: 0,
+            skip: source_size.0 * crop_offset.1 + crop_offset.0,
+            remaining: crop_size.0 * crop_size.1,
+        })
+    }
+
+    /// Mirrors the base 2D area horizontally or vertically.
+    ///
+    /// [`Flip2D::Horizontal`] reverses every row, [`Flip2D::Vertical`] reverses the order of the rows.
+    ///
+    /// # Arguments
+    /// * `source_size` - Size of the base 2D area as (width, height).
+    /// * `flip` - The axis to mirror along.
+    ///
+    /// # Panics
+    /// Panics if any size is zero or the area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Flip2D, Iter2D};
+    ///
+    /// let vec = (0..6).flip_2d((3, 2), Flip2D::Horizontal).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![2, 1, 0, 5, 4, 3]);
+    ///
+    /// let vec = (0..6).flip_2d((3, 2), Flip2D::Vertical).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![3, 4, 5, 0, 1, 2]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn flip_2d(self, source_size: (usize, usize), flip: Flip2D) -> Remap2D<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        self.try_flip_2d(source_size, flip)
+            .unwrap_or_else(|err| remap_layout_panic(err))
+    }
+
+    /// Mirrors the base 2D area horizontally or vertically, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`flip_2d`](Iter2D::flip_2d).
+    ///
+    /// # Errors
+    /// Returns [`IterExtdError::ZeroSize`] if any size is zero and [`IterExtdError::Overflow`]
+    /// if the area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Flip2D, Iter2D, IterExtdError};
+    ///
+    /// let res = (0..6).try_flip_2d((3, 0), Flip2D::Vertical);
+    /// assert_eq!(res.err(), Some(IterExtdError::ZeroSize));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn try_flip_2d(
+        self,
+        source_size: (usize, usize),
+        flip: Flip2D,
+    ) -> Result<Remap2D<Self>, IterExtdError>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Remap2D::new(self, source_size, source_size, Mapping2D::Flip(flip))
+    }
+
     /// Overlays a 2D region from another iterator (`overlay_iter`) onto the base iterator (`self`)
     /// at the specified offset and size within the target 2D area.
     ///
@@ -78,98 +256,1087 @@ pub trait Iter2D: Iterator {
         Self: Sized + Iterator<Item = T>,
         J: Iterator<Item = T>,
     {
-        let overlay_right_edge = overlay_offset.0 + overlay_size.0;
-
-        if (target_size.0 == 0)
-            || (target_size.1 == 0)
-            || (overlay_size.0 == 0)
-            || (overlay_size.1 == 0)
-        {
-            panic!("the size of abstract 2D data must not be zero");
-        }
-
-        if overlay_right_edge > target_size.0 || (overlay_offset.1 + overlay_size.1) > target_size.1
-        {
-            panic!("the offset and size of the inserted abstract 2D data exceed the bounds");
-        }
-
-        let overlay_start_index = target_size.0 * overlay_offset.1 + overlay_offset.0;
+        self.try_overlay_2d(overlay_iter, target_size, overlay_size, overlay_offset)
+            .unwrap_or_else(|err| overlay_layout_panic(err))
+    }
 
-        Overlay2D {
+    /// Overlays a 2D region from another iterator (`overlay_iter`) onto the base iterator (`self`),
+    /// returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`overlay_2d`](Iter2D::overlay_2d).
+    ///
+    /// # Errors
+    /// Returns [`IterExtdError::ZeroSize`] if any size is zero and [`IterExtdError::OutOfBounds`]
+    /// if the overlay region does not fit within the target area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Iter2D, IterExtdError};
+    ///
+    /// let res = (0..9).try_overlay_2d(0..4, (3, 3), (2, 2), (2, 2));
+    /// assert_eq!(res.err(), Some(IterExtdError::OutOfBounds));
+    ///
+    /// let vec = (0..9).try_overlay_2d(0..1, (3, 3), (1, 1), (2, 2)).unwrap().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 1, 2, 3, 4, 5, 6, 7, 0]);
+    /// ```
+    #[inline]
+    fn try_overlay_2d<J, T>(
+        self,
+        overlay_iter: J,
+        target_size: (usize, usize),
+        overlay_size: (usize, usize),
+        overlay_offset: (usize, usize),
+    ) -> Result<Overlay2D<Self, J, T>, IterExtdError>
+    where
+        Self: Sized + Iterator<Item = T>,
+        J: Iterator<Item = T>,
+    {
+        Ok(Overlay2D {
             base_iter: self,
             overlay_iter,
-            base_index: 0,
-            overlay_index: overlay_start_index,
-            overlay_row_width: if target_size.0 == overlay_size.0 {
-                overlay_size.0 * overlay_size.1
-            } else {
-                overlay_size.0
-            },
-            overlay_row_padding: if target_size.0 == overlay_size.0 {
-                let total_target = target_size.0 * target_size.1;
-                let total_overlay = overlay_size.0 * overlay_size.1;
-                total_target - (total_overlay + overlay_start_index)
-            } else {
-                overlay_offset.0 + (target_size.0 - overlay_right_edge)
-            },
-            in_overlay_row: false,
-        }
+            cursor: OverlayCursor::new(target_size, overlay_size, overlay_offset)?,
+        })
     }
-}
-
-/// Iterator adapter for overlaying one 2D sequence onto another.
-///
-/// Allows inserting elements from `overlay_iter` into the base iterator `base_iter`
-/// at a specified offset and size. Useful for abstract 2D structures represented as 1D iterators.
-#[derive(Debug, Clone)]
-pub struct Overlay2D<I: Iterator<Item = T>, J: Iterator<Item = T>, T> {
-    base_iter: I,
-    overlay_iter: J,
-    base_index: usize,
-    overlay_index: usize,
-    overlay_row_width: usize,
-    overlay_row_padding: usize,
-    in_overlay_row: bool,
-}
-
-impl<I, J, T> Iterator for Overlay2D<I, J, T>
-where
-    I: Iterator<Item = T>,
-    J: Iterator<Item = T>,
-{
-    type Item = I::Item;
 
+    /// Overlays a 2D region from another iterator (`overlay_iter`) onto the base iterator (`self`),
+    /// clipping the overlay region to the bounds of the target 2D area.
+    ///
+    /// The offset is signed, so the overlay region may start left of or above the target area
+    /// or extend past its right and bottom edges. Overlay elements that fall outside the target area
+    /// are consumed in row order and discarded.
+    ///
+    /// # Arguments
+    /// * `overlay_iter` - Iterator providing the elements to overlay.
+    /// * `target_size` - Size of the base 2D area as (width, height).
+    /// * `overlay_size` - Size of the overlay region as (width, height).
+    /// * `overlay_offset` - Signed offset (x, y) in the base area where the overlay region should be inserted.
+    ///
+    /// # Panics
+    /// Panics if any size is zero or an area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::Iter2D;
+    ///
+    /// let vec = [0; 9]
+    ///     .into_iter()
+    ///     .overlay_2d_clipped(1..=4, (3, 3), (2, 2), (-1, 2))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(vec, vec![0, 0, 0, 0, 0, 0, 2, 0, 0]);
+    /// ```
     #[inline]
-    fn next(&mut self) -> Option<T> {
-        let iter_item = self.base_iter.next();
+    fn overlay_2d_clipped<J>(
+        self,
+        overlay_iter: J,
+        target_size: (usize, usize),
+        overlay_size: (usize, usize),
+        overlay_offset: (isize, isize),
+    ) -> Overlay2DClipped<Self, J>
+    where
+        Self: Sized,
+        J: Iterator<Item = Self::Item>,
+    {
+        self.try_overlay_2d_clipped(overlay_iter, target_size, overlay_size, overlay_offset)
+            .unwrap_or_else(|err| overlay_layout_panic(err))
+    }
 
-        if self.base_index == self.overlay_index {
-            if self.in_overlay_row {
-                self.in_overlay_row = false;
-                self.overlay_index += self.overlay_row_padding;
-            } else {
-                self.in_overlay_row = true;
-                self.base_index += self.overlay_row_width;
-            }
-        }
+    /// Overlays a 2D region from another iterator (`overlay_iter`) onto the base iterator (`self`)
+    /// with clipping, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`overlay_2d_clipped`](Iter2D::overlay_2d_clipped).
+    ///
+    /// # Errors
+    /// Returns [`IterExtdError::ZeroSize`] if any size is zero and [`IterExtdError::Overflow`]
+    /// if an area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Iter2D, IterExtdError};
+    ///
+    /// let res = (0..9).try_overlay_2d_clipped(0..4, (3, 3), (0, 2), (-1, -1));
+    /// assert_eq!(res.err(), Some(IterExtdError::ZeroSize));
+    /// ```
+    #[inline]
+    fn try_overlay_2d_clipped<J>(
+        self,
+        overlay_iter: J,
+        target_size: (usize, usize),
+        overlay_size: (usize, usize),
+        overlay_offset: (isize, isize),
+    ) -> Result<Overlay2DClipped<Self, J>, IterExtdError>
+    where
+        Self: Sized,
+        J: Iterator<Item = Self::Item>,
+    {
+        area_2d(target_size)?;
+        area_2d(overlay_size)?;
 
-        if self.base_index > self.overlay_index {
-            self.overlay_index += 1;
-            let iter_other_item = self.overlay_iter.next();
-            if iter_other_item.is_none() || iter_item.is_none() {
-                self.overlay_index = usize::MAX;
-                return iter_item;
-            }
-            return iter_other_item;
-        } else {
-            self.base_index += 1;
-        }
+        Ok(Overlay2DClipped {
+            base_iter: self,
+            overlay_iter: overlay_iter.fuse(),
+            target_size,
+            overlay_size,
+            overlay_offset,
+            column: 0,
+            row: 0,
+            overlay_index: 0,
+        })
+    }
 
-        iter_item
+    /// Overlays a 2D region from another iterator (`overlay_iter`) onto the base iterator (`self`),
+    /// combining the overlapping elements with a closure.
+    ///
+    /// The closure receives the base element and the overlay element and returns the resulting element.
+    /// Useful for alpha compositing, masks and other blending operations.
+    ///
+    /// # Arguments
+    /// * `overlay_iter` - Iterator providing the elements to blend.
+    /// * `target_size` - Size of the base 2D area as (width, height).
+    /// * `overlay_size` - Size of the overlay region as (width, height).
+    /// * `overlay_offset` - Offset (x, y) in the base area where the overlay region should be blended.
+    /// * `f` - Closure combining a base element with an overlay element.
+    ///
+    /// # Panics
+    /// Panics if the overlay region does not fit within the target area or if any size is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::Iter2D;
+    ///
+    /// let base = vec![10u8; 9];
+    /// let mask = vec![1u8, 2, 3, 4];
+    ///
+    /// let vec = base
+    ///     .into_iter()
+    ///     .overlay_2d_with(mask.into_iter(), (3, 3), (2, 2), (1, 1), |base, over| base * over)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(vec, vec![10, 10, 10, 10, 10, 20, 10, 30, 40]);
+    /// ```
+    #[inline]
+    fn overlay_2d_with<J, F>(
+        self,
+        overlay_iter: J,
+        target_size: (usize, usize),
+        overlay_size: (usize, usize),
+        overlay_offset: (usize, usize),
+        f: F,
+    ) -> Overlay2DWith<Self, J, F>
+    where
+        Self: Sized,
+        J: Iterator,
+        F: FnMut(Self::Item, J::Item) -> Self::Item,
+    {
+        self.try_overlay_2d_with(overlay_iter, target_size, overlay_size, overlay_offset, f)
+            .unwrap_or_else(|err| overlay_layout_panic(err))
     }
 
+    /// Overlays a 2D region from another iterator (`overlay_iter`) onto the base iterator (`self`),
+    /// combining the overlapping elements with a closure and returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`overlay_2d_with`](Iter2D::overlay_2d_with).
+    ///
+    /// # Errors
+    /// Returns [`IterExtdError::ZeroSize`] if any size is zero and [`IterExtdError::OutOfBounds`]
+    /// if the overlay region does not fit within the target area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Iter2D, IterExtdError};
+    ///
+    /// let res = (0..9).try_overlay_2d_with(0..4, (3, 3), (2, 2), (2, 0), |base, over| base + over);
+    /// assert_eq!(res.err(), Some(IterExtdError::OutOfBounds));
+    /// ```
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.base_iter.size_hint()
+    fn try_overlay_2d_with<J, F>(
+        self,
+        overlay_iter: J,
+        target_size: (usize, usize),
+        overlay_size: (usize, usize),
+        overlay_offset: (usize, usize),
+        f: F,
+    ) -> Result<Overlay2DWith<Self, J, F>, IterExtdError>
+    where
+        Self: Sized,
+        J: Iterator,
+        F: FnMut(Self::Item, J::Item) -> Self::Item,
+    {
+        Ok(Overlay2DWith {
+            base_iter: self,
+            overlay_iter,
+            cursor: OverlayCursor::new(target_size, overlay_size, overlay_offset)?,
+            f,
+        })
+    }
+
+    /// Overlays a 2D region of optional elements onto the base iterator,
+    /// treating `None` overlay elements as transparent.
+    ///
+    /// # Arguments
+    /// * `overlay_iter` - Iterator providing the optional elements to overlay.
+    /// * `target_size` - Size of the base 2D area as (width, height).
+    /// * `overlay_size` - Size of the overlay region as (width, height).
+    /// * `overlay_offset` - Offset (x, y) in the base area where the overlay region should be inserted.
+    ///
+    /// # Panics
+    /// Panics if the overlay region does not fit within the target area or if any size is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::Iter2D;
+    ///
+    /// let sprite = [None, Some('■'), Some('■'), None];
+    ///
+    /// let vec = ['□'; 9]
+    ///     .into_iter()
+    ///     .overlay_2d_transparent(sprite.into_iter(), (3, 3), (2, 2), (0, 1))
+    ///     .collect::<String>();
+    ///
+    /// assert_eq!(vec, "□□□□■□■□□");
+    /// ```
+    #[inline]
+    fn overlay_2d_transparent<J, T>(
+        self,
+        overlay_iter: J,
+        target_size: (usize, usize),
+        overlay_size: (usize, usize),
+        overlay_offset: (usize, usize),
+    ) -> Overlay2DTransparent<Self, J, T>
+    where
+        Self: Sized + Iterator<Item = T>,
+        J: Iterator<Item = Option<T>>,
+    {
+        self.try_overlay_2d_transparent(overlay_iter, target_size, overlay_size, overlay_offset)
+            .unwrap_or_else(|err| overlay_layout_panic(err))
+    }
+
+    /// Overlays a 2D region of optional elements onto the base iterator,
+    /// returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`overlay_2d_transparent`](Iter2D::overlay_2d_transparent).
+    ///
+    /// # Errors
+    /// Returns [`IterExtdError::ZeroSize`] if any size is zero and [`IterExtdError::OutOfBounds`]
+    /// if the overlay region does not fit within the target area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Iter2D, IterExtdError};
+    ///
+    /// let res = (0..9).try_overlay_2d_transparent([Some(1)].into_iter(), (3, 3), (1, 1), (3, 0));
+    /// assert_eq!(res.err(), Some(IterExtdError::OutOfBounds));
+    /// ```
+    #[inline]
+    fn try_overlay_2d_transparent<J, T>(
+        self,
+        overlay_iter: J,
+        target_size: (usize, usize),
+        overlay_size: (usize, usize),
+        overlay_offset: (usize, usize),
+    ) -> Result<Overlay2DTransparent<Self, J, T>, IterExtdError>
+    where
+        Self: Sized + Iterator<Item = T>,
+        J: Iterator<Item = Option<T>>,
+    {
+        self.try_overlay_2d_with(
+            overlay_iter,
+            target_size,
+            overlay_size,
+            overlay_offset,
+            |base, over| over.unwrap_or(base),
+        )
+    }
+
+    /// Rotates the base 2D area clockwise by the given angle.
+    ///
+    /// A rotation by 90 or 270 degrees swaps the width and height of the yielded 2D area.
+    ///
+    /// # Arguments
+    /// * `source_size` - Size of the base 2D area as (width, height).
+    /// * `rotation` - The clockwise rotation angle.
+    ///
+    /// # Panics
+    /// Panics if any size is zero or the area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Iter2D, Rotation2D};
+    ///
+    /// let vec = (0..6).rotate_2d((3, 2), Rotation2D::Deg90).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![3, 0, 4, 1, 5, 2]);
+    ///
+    /// let vec = (0..6).rotate_2d((3, 2), Rotation2D::Deg180).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![5, 4, 3, 2, 1, 0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn rotate_2d(self, source_size: (usize, usize), rotation: Rotation2D) -> Remap2D<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        self.try_rotate_2d(source_size, rotation)
+            .unwrap_or_else(|err| remap_layout_panic(err))
+    }
+
+    /// Rotates the base 2D area clockwise by the given angle, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`rotate_2d`](Iter2D::rotate_2d).
+    ///
+    /// # Errors
+    /// Returns [`IterExtdError::ZeroSize`] if any size is zero and [`IterExtdError::Overflow`]
+    /// if the area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Iter2D, IterExtdError, Rotation2D};
+    ///
+    /// let res = (0..6).try_rotate_2d((0, 2), Rotation2D::Deg90);
+    /// assert_eq!(res.err(), Some(IterExtdError::ZeroSize));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn try_rotate_2d(
+        self,
+        source_size: (usize, usize),
+        rotation: Rotation2D,
+    ) -> Result<Remap2D<Self>, IterExtdError>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        let output_size = match rotation {
+            Rotation2D::Deg180 => source_size,
+            Rotation2D::Deg90 | Rotation2D::Deg270 => (source_size.1, source_size.0),
+        };
+
+        Remap2D::new(self, source_size, output_size, Mapping2D::Rotate(rotation))
+    }
+
+    /// Repeats the base 2D area as a grid of tiles.
+    ///
+    /// # Arguments
+    /// * `source_size` - Size of the base 2D area as (width, height).
+    /// * `tiles` - Number of tiles as (columns, rows).
+    ///
+    /// # Panics
+    /// Panics if any size or the number of tiles is zero, or if the tiled area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::Iter2D;
+    ///
+    /// let vec = (0..4).tile_2d((2, 2), (2, 1)).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 1, 0, 1, 2, 3, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn tile_2d(self, source_size: (usize, usize), tiles: (usize, usize)) -> Remap2D<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        self.try_tile_2d(source_size, tiles)
+            .unwrap_or_else(|err| remap_layout_panic(err))
+    }
+
+    /// Repeats the base 2D area as a grid of tiles, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`tile_2d`](Iter2D::tile_2d).
+    ///
+    /// # Errors
+    /// Returns [`IterExtdError::ZeroSize`] if any size or the number of tiles is zero
+    /// and [`IterExtdError::Overflow`] if the tiled area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Iter2D, IterExtdError};
+    ///
+    /// let res = (0..4).try_tile_2d((2, 2), (usize::MAX, 1));
+    /// assert_eq!(res.err(), Some(IterExtdError::Overflow));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn try_tile_2d(
+        self,
+        source_size: (usize, usize),
+        tiles: (usize, usize),
+    ) -> Result<Remap2D<Self>, IterExtdError>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        area_2d(source_size)?;
+        area_2d(tiles)?;
+        let output_size = match (
+            source_size.0.checked_mul(tiles.0),
+            source_size.1.checked_mul(tiles.1),
+        ) {
+            (Some(width), Some(height)) => (width, height),
+            _ => return Err(IterExtdError::Overflow),
+        };
+
+        Remap2D::new(self, source_size, output_size, Mapping2D::Tile)
+    }
+
+    /// Transposes the base 2D area, turning its rows into columns.
+    ///
+    /// # Arguments
+    /// * `source_size` - Size of the base 2D area as (width, height).
+    ///
+    /// # Panics
+    /// Panics if any size is zero or the area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::Iter2D;
+    ///
+    /// let vec = (0..6).transpose_2d((3, 2)).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn transpose_2d(self, source_size: (usize, usize)) -> Remap2D<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        self.try_transpose_2d(source_size)
+            .unwrap_or_else(|err| remap_layout_panic(err))
+    }
+
+    /// Transposes the base 2D area, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`transpose_2d`](Iter2D::transpose_2d).
+    ///
+    /// # Errors
+    /// Returns [`IterExtdError::ZeroSize`] if any size is zero and [`IterExtdError::Overflow`]
+    /// if the area overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterextd::{Iter2D, IterExtdError};
+    ///
+    /// let res = (0..6).try_transpose_2d((usize::MAX, 2));
+    /// assert_eq!(res.err(), Some(IterExtdError::Overflow));
+    ///
+    /// let vec = (0..6).try_transpose_2d((3, 2)).unwrap().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn try_transpose_2d(self, source_size: (usize, usize)) -> Result<Remap2D<Self>, IterExtdError>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Remap2D::new(
+            self,
+            source_size,
+            (source_size.1, source_size.0),
+            Mapping2D::Transpose,
+        )
+    }
+}
+
+/// Returns the number of elements of a 2D area.
+#[inline]
+fn area_2d(size: (usize, usize)) -> Result<usize, IterExtdError> {
+    if (size.0 == 0) || (size.1 == 0) {
+        return Err(IterExtdError::ZeroSize);
+    }
+
+    size.0.checked_mul(size.1).ok_or(IterExtdError::Overflow)
+}
+
+/// Checks that a region starting at `offset` with the given `size` fits within `bound`.
+#[inline]
+fn fits_2d(offset: usize, size: usize, bound: usize) -> bool {
+    offset.checked_add(size).is_some_and(|edge| edge <= bound)
+}
+
+/// Panics with the message matching the remap layout error.
+#[cfg(feature = "alloc")]
+#[inline]
+fn remap_layout_panic(err: IterExtdError) -> ! {
+    match err {
+        IterExtdError::ZeroSize => panic!("the size of abstract 2D data must not be zero"),
+        _ => panic!("the size of abstract 2D data overflows usize"),
+    }
+}
+
+/// Panics with the message matching the overlay layout error.
+#[inline]
+fn overlay_layout_panic(err: IterExtdError) -> ! {
+    match err {
+        IterExtdError::ZeroSize => panic!("the size of abstract 2D data must not be zero"),
+        IterExtdError::Overflow => panic!("the size of abstract 2D data overflows usize"),
+        _ => panic!("the offset and size of the inserted abstract 2D data exceed the bounds"),
+    }
+}
+
+/// The position of the base iterator relative to the overlay region,
+/// shared by [`Overlay2D`] and [`Overlay2DWith`].
+#[derive(Debug, Clone, Copy)]
+struct OverlayCursor {
+    base_index: usize,
+    overlay_index: usize,
+    overlay_row_width: usize,
+    overlay_row_padding: usize,
+    in_overlay_row: bool,
+}
+
+impl OverlayCursor {
+    #[inline]
+    fn new(
+        target_size: (usize, usize),
+        overlay_size: (usize, usize),
+        overlay_offset: (usize, usize),
+    ) -> Result<Self, IterExtdError> {
+        let total_target = area_2d(target_size)?;
+        let total_overlay = area_2d(overlay_size)?;
+
+        if !fits_2d(overlay_offset.0, overlay_size.0, target_size.0)
+            || !fits_2d(overlay_offset.1, overlay_size.1, target_size.1)
+        {
+            return Err(IterExtdError::OutOfBounds);
+        }
+
+        let overlay_start_index = target_size.0 * overlay_offset.1 + overlay_offset.0;
+
+        let (overlay_row_width, overlay_row_padding) = if target_size.0 == overlay_size.0 {
+            (
+                total_overlay,
+                total_target - (total_overlay + overlay_start_index),
+            )
+        } else {
+            (overlay_size.0, target_size.0 - overlay_size.0)
+        };
+
+        Ok(OverlayCursor {
+            base_index: 0,
+            overlay_index: overlay_start_index,
+            overlay_row_width,
+            overlay_row_padding,
+            in_overlay_row: false,
+        })
+    }
+
+    /// Advances the cursor by one base element and returns `true` if it lies within the overlay region.
+    #[inline]
+    fn advance(&mut self) -> bool {
+        if self.base_index == self.overlay_index {
+            if self.in_overlay_row {
+                self.in_overlay_row = false;
+                self.overlay_index += self.overlay_row_padding;
+            } else {
+                self.in_overlay_row = true;
+                self.base_index += self.overlay_row_width;
+            }
+        }
+
+        if self.base_index > self.overlay_index {
+            self.overlay_index += 1;
+            true
+        } else {
+            self.base_index += 1;
+            false
+        }
+    }
+
+    /// Leaves the overlay region for good, once the overlay iterator is exhausted.
+    #[inline]
+    fn finish(&mut self) {
+        self.overlay_index = usize::MAX;
+    }
+
+    #[inline]
+    fn debug_fields(&self, fmt: &mut fmt::DebugStruct<'_, '_>) {
+        fmt.field("base_index", &self.base_index)
+            .field("overlay_index", &self.overlay_index)
+            .field("overlay_row_width", &self.overlay_row_width)
+            .field("overlay_row_padding", &self.overlay_row_padding)
+            .field("in_overlay_row", &self.in_overlay_row);
+    }
+}
+
+/// Iterator adapter for overlaying one 2D sequence onto another.
+///
+/// Allows inserting elements from `overlay_iter` into the base iterator `base_iter`
+/// at a specified offset and size. Useful for abstract 2D structures represented as 1D iterators.
+#[derive(Clone)]
+pub struct Overlay2D<I: Iterator<Item = T>, J: Iterator<Item = T>, T> {
+    base_iter: I,
+    overlay_iter: J,
+    cursor: OverlayCursor,
+}
+
+impl<I, J, T> fmt::Debug for Overlay2D<I, J, T>
+where
+    I: Iterator<Item = T> + fmt::Debug,
+    J: Iterator<Item = T> + fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fmt = fmt.debug_struct("Overlay2D");
+        fmt.field("base_iter", &self.base_iter)
+            .field("overlay_iter", &self.overlay_iter);
+        self.cursor.debug_fields(&mut fmt);
+        fmt.finish()
+    }
+}
+
+impl<I, J, T> Iterator for Overlay2D<I, J, T>
+where
+    I: Iterator<Item = T>,
+    J: Iterator<Item = T>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let iter_item = self.base_iter.next()?;
+
+        if self.cursor.advance() {
+            match self.overlay_iter.next() {
+                Some(iter_other_item) => return Some(iter_other_item),
+                None => self.cursor.finish(),
+            }
+        }
+
+        Some(iter_item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.base_iter.size_hint()
+    }
+}
+
+impl<I, J> FusedIterator for Overlay2DClipped<I, J>
+where
+    I: FusedIterator,
+    J: Iterator<Item = I::Item>,
+{
+}
+
+impl<I, J> ExactSizeIterator for Overlay2DClipped<I, J>
+where
+    I: ExactSizeIterator,
+    J: Iterator<Item = I::Item>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.base_iter.len()
+    }
+}
+
+/// Iterator adapter for overlaying one 2D sequence onto another with clipping.
+///
+/// Unlike [`Overlay2D`], the overlay region may be placed partially or entirely outside the target area.
+#[derive(Debug, Clone)]
+pub struct Overlay2DClipped<I, J> {
+    base_iter: I,
+    overlay_iter: Fuse<J>,
+    target_size: (usize, usize),
+    overlay_size: (usize, usize),
+    overlay_offset: (isize, isize),
+    column: usize,
+    row: usize,
+    overlay_index: usize,
+}
+
+impl<I, J> Overlay2DClipped<I, J> {
+    /// Returns the index of the overlay element at the current base position, if any.
+    #[inline]
+    fn overlay_cell(&self) -> Option<usize> {
+        if self.row >= self.target_size.1 {
+            return None;
+        }
+
+        let x = (self.column as isize).checked_sub(self.overlay_offset.0)?;
+        let y = (self.row as isize).checked_sub(self.overlay_offset.1)?;
+        if x < 0 || y < 0 || x as usize >= self.overlay_size.0 || y as usize >= self.overlay_size.1
+        {
+            return None;
+        }
+
+        Some(y as usize * self.overlay_size.0 + x as usize)
+    }
+}
+
+impl<I, J> Iterator for Overlay2DClipped<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter_item = self.base_iter.next()?;
+        let overlay_cell = self.overlay_cell();
+
+        self.column += 1;
+        if self.column == self.target_size.0 {
+            self.column = 0;
+            self.row += 1;
+        }
+
+        if let Some(index) = overlay_cell {
+            let iter_other_item = self.overlay_iter.nth(index - self.overlay_index);
+            self.overlay_index = index + 1;
+            if iter_other_item.is_some() {
+                return iter_other_item;
+            }
+        }
+
+        Some(iter_item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.base_iter.size_hint()
+    }
+}
+
+impl<I, J, F> FusedIterator for Overlay2DWith<I, J, F>
+where
+    I: FusedIterator,
+    J: Iterator,
+    F: FnMut(I::Item, J::Item) -> I::Item,
+{
+}
+
+impl<I, J, F> ExactSizeIterator for Overlay2DWith<I, J, F>
+where
+    I: ExactSizeIterator,
+    J: Iterator,
+    F: FnMut(I::Item, J::Item) -> I::Item,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.base_iter.len()
+    }
+}
+
+/// Iterator adapter returned by [`overlay_2d_transparent`](Iter2D::overlay_2d_transparent).
+pub type Overlay2DTransparent<I, J, T> = Overlay2DWith<I, J, fn(T, Option<T>) -> T>;
+
+/// Iterator adapter for blending one 2D sequence onto another with a closure.
+///
+/// Works like [`Overlay2D`], but passes both the base and the overlay element to the closure
+/// instead of replacing the base element.
+#[derive(Clone)]
+pub struct Overlay2DWith<I, J, F> {
+    base_iter: I,
+    overlay_iter: J,
+    cursor: OverlayCursor,
+    f: F,
+}
+
+impl<I, J, F> fmt::Debug for Overlay2DWith<I, J, F>
+where
+    I: fmt::Debug,
+    J: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fmt = fmt.debug_struct("Overlay2DWith");
+        fmt.field("base_iter", &self.base_iter)
+            .field("overlay_iter", &self.overlay_iter);
+        self.cursor.debug_fields(&mut fmt);
+        fmt.field("f", &format_args!("{:p}", &self.f)).finish()
+    }
+}
+
+impl<I, J, F> Iterator for Overlay2DWith<I, J, F>
+where
+    I: Iterator,
+    J: Iterator,
+    F: FnMut(I::Item, J::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter_item = self.base_iter.next()?;
+
+        if self.cursor.advance() {
+            match self.overlay_iter.next() {
+                Some(iter_other_item) => return Some((self.f)(iter_item, iter_other_item)),
+                None => self.cursor.finish(),
+            }
+        }
+
+        Some(iter_item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.base_iter.size_hint()
+    }
+}
+
+/// Iterator adapter for cropping a rectangular region out of a 2D sequence.
+///
+/// The elements outside the cropped region are skipped, so the base iterator is consumed only once.
+#[derive(Debug, Clone)]
+pub struct Crop2D<I> {
+    iter: I,
+    source_width: usize,
+    crop_width: usize,
+    column: usize,
+    skip: usize,
+    remaining: usize,
+}
+
+impl<I> Crop2D<I> {
+    #[inline]
+    fn available(&self, len: usize) -> usize {
+        let Some(len) = len.checked_sub(self.skip) else {
+            return 0;
+        };
+        let row_rest = self.crop_width - self.column;
+        if len <= row_rest {
+            return len.min(self.remaining);
+        }
+
+        let len = len - row_rest;
+        let row_gap = self.source_width - self.crop_width;
+        let count = if len <= row_gap {
+            row_rest
+        } else {
+            let len = len - row_gap;
+            row_rest
+                + (len / self.source_width) * self.crop_width
+                + (len % self.source_width).min(self.crop_width)
+        };
+
+        count.min(self.remaining)
+    }
+}
+
+impl<I> Iterator for Crop2D<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let item = self.iter.nth(self.skip);
+        self.remaining -= 1;
+        self.column += 1;
+        if self.column == self.crop_width {
+            self.column = 0;
+            self.skip = self.source_width - self.crop_width;
+        } else {
+            self.skip = 0;
+        }
+
+        if item.is_none() {
+            self.remaining = 0;
+        }
+
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remaining == 0 {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iter.size_hint();
+
+        (
+            self.available(lower),
+            Some(upper.map_or(self.remaining, |upper| self.available(upper))),
+        )
+    }
+}
+
+/// The clockwise rotation angle for the [`rotate_2d`](Iter2D::rotate_2d) adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation2D {
+    /// Rotation by 90 degrees.
+    Deg90,
+    /// Rotation by 180 degrees.
+    Deg180,
+    /// Rotation by 270 degrees.
+    Deg270,
+}
+
+/// The mirroring axis for the [`flip_2d`](Iter2D::flip_2d) adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flip2D {
+    /// Reverses the elements of every row.
+    Horizontal,
+    /// Reverses the order of the rows.
+    Vertical,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy)]
+enum Mapping2D {
+    Flip(Flip2D),
+    Rotate(Rotation2D),
+    Tile,
+    Transpose,
+}
+
+#[cfg(feature = "alloc")]
+impl Mapping2D {
+    #[inline]
+    fn source_index(self, (x, y): (usize, usize), (width, height): (usize, usize)) -> usize {
+        let (column, row) = match self {
+            Mapping2D::Flip(Flip2D::Horizontal) => (width - 1 - x, y),
+            Mapping2D::Flip(Flip2D::Vertical) => (x, height - 1 - y),
+            Mapping2D::Rotate(Rotation2D::Deg90) => (y, height - 1 - x),
+            Mapping2D::Rotate(Rotation2D::Deg180) => (width - 1 - x, height - 1 - y),
+            Mapping2D::Rotate(Rotation2D::Deg270) => (width - 1 - y, x),
+            Mapping2D::Tile => (x % width, y % height),
+            Mapping2D::Transpose => (y, x),
+        };
+
+        row * width + column
+    }
+}
+
+/// Iterator adapter that reorders the elements of a 2D sequence.
+///
+/// Used by the [`transpose_2d`](Iter2D::transpose_2d), [`rotate_2d`](Iter2D::rotate_2d),
+/// [`flip_2d`](Iter2D::flip_2d) and [`tile_2d`](Iter2D::tile_2d) adapters. The base iterator
+/// is read into a buffer on the first call to `next`, the elements are then cloned out of it.
+/// The base iterator should yield at least `width * height` elements, the iteration stops at the first missing element.
+/// The length is known exactly if the base iterator is an [`ExactSizeIterator`] or after the first call to `next`.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct Remap2D<I: Iterator> {
+    base_iter: Option<I>,
+    buffer: Vec<I::Item>,
+    source_size: (usize, usize),
+    output_size: (usize, usize),
+    output_len: usize,
+    output_index: usize,
+    mapping: Mapping2D,
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Remap2D<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    #[inline]
+    fn new(
+        base_iter: I,
+        source_size: (usize, usize),
+        output_size: (usize, usize),
+        mapping: Mapping2D,
+    ) -> Result<Self, IterExtdError> {
+        area_2d(source_size)?;
+        let output_len = area_2d(output_size)?;
+
+        Ok(Remap2D {
+            base_iter: Some(base_iter),
+            buffer: Vec::new(),
+            source_size,
+            output_size,
+            output_len,
+            output_index: 0,
+            mapping,
+        })
+    }
+
+    #[inline]
+    fn source_len(&self) -> usize {
+        self.source_size.0 * self.source_size.1
+    }
+
+    #[inline]
+    fn source_index(&self, output_index: usize) -> usize {
+        let position = (
+            output_index % self.output_size.0,
+            output_index / self.output_size.0,
+        );
+        self.mapping.source_index(position, self.source_size)
+    }
+
+    /// Returns the number of remaining elements if the base iterator yields `available` elements,
+    /// the output ends at the first position mapped outside of them.
+    #[inline]
+    fn remaining_with(&self, available: usize) -> usize {
+        let remaining = self.output_len - self.output_index;
+        if available >= self.source_len() {
+            return remaining;
+        }
+
+        (self.output_index..self.output_len)
+            .position(|index| self.source_index(index) >= available)
+            .unwrap_or(remaining)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Iterator for Remap2D<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.output_index == self.output_len {
+            return None;
+        }
+
+        if let Some(base_iter) = self.base_iter.take() {
+            self.buffer = base_iter.take(self.source_len()).collect();
+            self.output_len = self.output_index + self.remaining_with(self.buffer.len());
+            if self.output_index == self.output_len {
+                return None;
+            }
+        }
+
+        let item = self.buffer[self.source_index(self.output_index)].clone();
+        self.output_index += 1;
+
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.base_iter {
+            Some(base_iter) => {
+                let (lower, upper) = base_iter.size_hint();
+                (
+                    self.remaining_with(lower),
+                    Some(self.remaining_with(upper.unwrap_or(usize::MAX))),
+                )
+            }
+            None => {
+                let remaining = self.output_len - self.output_index;
+                (remaining, Some(remaining))
+            }
+        }
     }
 }
@@ -1,22 +1,41 @@
+use crate::structs::checked_gcd;
+#[cfg(feature = "alloc")]
 use crate::structs::{
-    Adapter, ArrChunks, ArrayCloned, ArrayCopied, CombineIters, Extrapolate, InclusiveStepBy,
-    LastTaken, MapByThree, MapByTwo, MapIters, MissingIntegers, Offset, Previous, RangeIcvToTup,
-    RangeToTup, SkipStepBy, SliceCopied, StepBoundary, StepByFn, TakeSkipCyclic, TupToRange,
-    TupToRangeIcv, TupleImut, TupleMut, UniqueSorted,
+    checked_extended_gcd, interpolate_value, is_fractional, FreqOrder, HistogramBin,
+    MissingRangesIn, UnexpectedIntegersIn, UniqueSorted, UniqueSortedStrategy,
+};
+use crate::structs::{
+    Adapter, ArrChunks, ArrayCloned, ArrayCopied, ArrayWindows, CombineIters, ConsecutiveRanges,
+    ExpandRanges, ExpandRuns, Extrapolate, ExtrapolateN, ExtrapolateWith, Extrapolation,
+    InclusiveStepBy, Interpolate, LastTaken, MapBy, MapByPad, MapByRest, MapByThree, MapByTwo,
+    MapIters, MissingIntegers, MissingRanges, Offset, PartialArray, Previous, RangeIcvToTup,
+    RangeToTup, Rounding, Runs, SetOp, SetOpSorted, SkipStepBy, SliceCopied, Stats, StepBoundary,
+    StepByFn, TakeSkipCyclic, TupToRange, TupToRangeIcv, TupleImut, TupleMut,
 };
 use crate::swap;
+#[cfg(feature = "alloc")]
+use crate::BTreeMap;
+#[cfg(feature = "alloc")]
+use crate::CheckedRem;
 use crate::Debug;
-use crate::FixedBitSet;
 use crate::FusedIterator;
 use crate::IntoIter;
-use crate::Itertools;
+use crate::IterExtdError;
 use crate::MaybeUninit;
-use crate::MinMax;
 use crate::PhantomData;
+#[cfg(feature = "alloc")]
+use crate::Signed;
+#[cfg(feature = "alloc")]
+use crate::TryFromByAdd;
+#[cfg(feature = "alloc")]
+use crate::Vec;
 use crate::Zero;
 use crate::{gcd, Integer};
-use crate::{Deref, Range};
-use crate::{ToZero, TryFromByAdd};
+use crate::{Add, Deref, Div, Mul, RangeInclusive, Sub};
+#[cfg(feature = "alloc")]
+use crate::{Bound, RangeBounds};
+use crate::{CheckedMul, CheckedSub};
+use crate::{NumCast, PrimInt};
 
 #[cfg(feature = "std")]
 use crate::Hash;
@@ -94,7 +113,7 @@ pub trait IterExtd: Iterator {
         Self: Sized,
     {
         assert!(N != 0, "chunk size must be non-zero");
-        ArrChunks { iter: self }
+        ArrChunks::new(self)
     }
 
     /// Creates an iterator that clones all elements of its arrays.
@@ -141,6 +160,34 @@ pub trait IterExtd: Iterator {
         ArrayCopied::new(self)
     }
 
+    /// Creates an iterator over overlapping windows of N elements.
+    ///
+    /// The last N elements are kept in an internal ring buffer and cloned into each window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if N is 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let iter = (0..6).array_windows::<3>();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+    /// ```
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        assert!(N != 0, "window size must be non-zero");
+        ArrayWindows::new(self)
+    }
+
     /// Collect a zeroed array for nullable types.
     ///
     /// # Panics
@@ -180,9 +227,11 @@ pub trait IterExtd: Iterator {
 
     /// Create an array from an iterator.
     ///
+    /// An empty array is returned for `N == 0` without advancing the iterator.
+    ///
     /// # Panics
     ///
-    /// Panics if N is 0 or N greater than the length of the iterator.
+    /// Panics if N is greater than the length of the iterator.
     ///
     /// # Examples
     ///
@@ -200,8 +249,33 @@ pub trait IterExtd: Iterator {
     where
         Self: Sized + Iterator,
     {
-        assert!(N != 0, "chunk size must be non-zero");
-        self.arr_chunks::<N>().next().unwrap()
+        match self.try_collect_array() {
+            Ok(arr) => arr,
+            Err(_) => panic!("iterator yielded fewer elements than the array size"),
+        }
+    }
+
+    /// Collapses sorted integers into ranges of consecutive integers.
+    ///
+    /// Equal consecutive integers are merged into the same range.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [1u8, 2, 3, 5, 7, 8, 8, 9, 255];
+    /// let vec = arr.into_iter().consecutive_ranges().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![1..=3, 5..=5, 7..=9, 255..=255]);
+    /// ```
+    fn consecutive_ranges(self) -> ConsecutiveRanges<Self>
+    where
+        Self: Sized,
+        Self::Item: PrimInt,
+    {
+        ConsecutiveRanges::new(self)
     }
 
     /// Consumes an iterator, returns nothing.
@@ -278,6 +352,8 @@ pub trait IterExtd: Iterator {
     /// # Warning
     ///
     /// The results are stored in a `HashMap`, which does not preserve the order of the elements.
+    /// Use [`count_freq_sorted`](IterExtd::count_freq_sorted) or
+    /// [`count_freq_first_seen`](IterExtd::count_freq_first_seen) for a deterministic order.
     ///
     /// # Examples
     ///
@@ -308,45 +384,56 @@ pub trait IterExtd: Iterator {
         hash_map.into_iter()
     }
 
-    /// Extrapolates the iterator's elements.
+    /// Counts the frequency of each element in the iterator into a fixed-capacity array,
+    /// in the order of first appearance.
     ///
-    /// # Panics
+    /// No allocation is performed, so neither `std` nor `alloc` is required. Each element is
+    /// looked up linearly, which makes this suitable for a small number of distinct elements.
     ///
-    /// This method will panic in debug mode if the extrapolated values cause a computation overflow.
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::CapacityExceeded`] if more than `N` distinct elements appear.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// use iterextd::IterExtd;
+    /// use iterextd::{IterExtd, IterExtdError};
     ///
-    /// let arr: [f32; 2] = [0.00, 0.01];
-    /// let extrapolated = arr.into_iter().extrapolate().take(5).collect::<Vec<_>>();
-    /// assert_eq!(extrapolated, vec![0.0, 0.01, 0.02, 0.03, 0.04]);
+    /// let freqs = "abracadabra".chars().count_freq_into::<5>().unwrap();
+    /// assert_eq!(freqs.as_slice(), &[('a', 5), ('b', 2), ('r', 2), ('c', 1), ('d', 1)]);
     ///
-    /// let vec = vec![2, 5, 6, 9, 13];
-    /// let extrapolated: Vec<_> = vec.into_iter().extrapolate().take(10).collect();
-    /// assert_eq!(extrapolated, vec![2, 5, 6, 9, 13, 17, 21, 25, 29, 33]);
+    /// let err = "abracadabra".chars().count_freq_into::<4>().unwrap_err();
+    /// assert_eq!(err, IterExtdError::CapacityExceeded);
     /// ```
-    fn extrapolate(self) -> Extrapolate<Self>
+    fn count_freq_into<const N: usize>(
+        self,
+    ) -> Result<PartialArray<(Self::Item, usize), N>, IterExtdError>
     where
         Self: Sized,
-        Self::Item: Zero,
+        Self::Item: PartialEq,
     {
-        Extrapolate {
-            iter: self,
-            arg_one: Self::Item::zero(),
-            arg_two: Self::Item::zero(),
+        let mut freqs = PartialArray::new();
+        for item in self {
+            match freqs
+                .as_mut_slice()
+                .iter_mut()
+                .find(|(key, _)| *key == item)
+            {
+                Some((_, count)) => *count += 1,
+                None => freqs
+                    .push((item, 1))
+                    .map_err(|_| IterExtdError::CapacityExceeded)?,
+            }
         }
+
+        Ok(freqs)
     }
 
-    /// Finds the greatest common divisor (GCD) of the elements in the iterator.
+    /// Counts the frequency of each element in the iterator in the order of first appearance.
     ///
-    /// # Notes
-    ///
-    /// - **NOTE:** This method will return `None` if the iterator is empty.
-    /// - **NOTE:** The minimum sequence length must be two elements; otherwise, `None` will be returned.
+    /// The counts are stored in a `BTreeMap`, so `std` is not required.
     ///
     /// # Examples
     ///
@@ -355,60 +442,75 @@ pub trait IterExtd: Iterator {
     /// ```
     /// use iterextd::IterExtd;
     ///
-    /// let vec = vec![24, 36, 48];
-    /// assert_eq!(vec.iter().gcd(), Some(12));
-    ///
-    /// let empty_vec: Vec<i32> = vec![];
-    /// assert_eq!(empty_vec.iter().gcd(), None);
-    ///
-    /// let single_element = vec![42];
-    /// assert_eq!(single_element.iter().gcd(), None);
+    /// let freqs = "abracadabra".chars().count_freq_first_seen().collect::<Vec<_>>();
+    /// assert_eq!(freqs, vec![('a', 5), ('b', 2), ('r', 2), ('c', 1), ('d', 1)]);
     /// ```
-    fn gcd(mut self) -> Option<<<Self as Iterator>::Item as Deref>::Target>
+    #[cfg(feature = "alloc")]
+    fn count_freq_first_seen(self) -> impl Iterator<Item = (Self::Item, usize)> + Debug
     where
         Self: Sized,
-        <Self as Iterator>::Item: Deref,
-        <<Self as Iterator>::Item as Deref>::Target: Integer + Copy,
+        Self::Item: Ord + Debug,
     {
-        let acc = gcd(*self.next()?, *self.next()?);
-        Some(self.fold(acc, |acc, x| gcd(acc, *x)))
+        let mut btree_map = BTreeMap::new();
+        for (idx, item) in self.enumerate() {
+            btree_map.entry(item).or_insert((idx, 0)).1 += 1;
+        }
+
+        let mut freqs = btree_map.into_iter().collect::<Vec<_>>();
+        freqs.sort_unstable_by_key(|&(_, (idx, _))| idx);
+        freqs.into_iter().map(|(item, (_, count))| (item, count))
     }
 
-    /// Create an indexes iterator with a start and end for each step.
-    ///
-    /// With each iteration, it furnishes the start and end indices of the current step,
-    /// while also accounting for any residual elements in the last incomplete step.
-    ///
-    /// # Panics
+    /// Counts the frequency of each element in the iterator in the given order.
     ///
-    /// Panics if the step size is zero.
+    /// [`FreqOrder::Key`] yields the elements in ascending order, [`FreqOrder::Count`] yields
+    /// the most frequent elements first, equal counts in ascending order of the elements.
+    /// The counts are stored in a `BTreeMap`, so `std` is not required.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// use iterextd::IterExtd;
+    /// use iterextd::{FreqOrder, IterExtd};
     ///
-    /// let s = "slice index iterator";
-    /// let iter = (0..s.len()).step_boundary(3);
-    /// iter.for_each(|bounds| { println!("{:?}", &s[bounds.0..=bounds.1] ); });
+    /// let freqs = "abracadabra".chars().count_freq_sorted(FreqOrder::Key).collect::<Vec<_>>();
+    /// assert_eq!(freqs, vec![('a', 5), ('b', 2), ('c', 1), ('d', 1), ('r', 2)]);
+    ///
+    /// let freqs = "abracadabra".chars().count_freq_sorted(FreqOrder::Count).collect::<Vec<_>>();
+    /// assert_eq!(freqs, vec![('a', 5), ('b', 2), ('r', 2), ('c', 1), ('d', 1)]);
     /// ```
-    fn step_boundary(self, size: usize) -> StepBoundary<Self>
+    #[cfg(feature = "alloc")]
+    fn count_freq_sorted(
+        self,
+        order: FreqOrder,
+    ) -> impl Iterator<Item = (Self::Item, usize)> + Debug
     where
         Self: Sized,
+        Self::Item: Ord + Debug,
     {
-        assert!(size != 0);
-        StepBoundary::new(self, size)
+        let mut btree_map = BTreeMap::new();
+        for item in self {
+            *btree_map.entry(item).or_insert(0) += 1;
+        }
+
+        let mut freqs = btree_map.into_iter().collect::<Vec<_>>();
+        if order == FreqOrder::Count {
+            // The stable sort keeps equal counts in the ascending order of the elements.
+            freqs.sort_by(|(_, lhs), (_, rhs)| rhs.cmp(lhs));
+        }
+        freqs.into_iter()
     }
 
-    /// Creates an iterator that performs the given step at each iteration, returning inclusive of the first and last element.
+    /// Creates an iterator of the sorted elements that are not in the other sorted iterator.
     ///
-    /// If there are elements smaller than the remaining step, the last element will be returned.
+    /// Both iterators must be sorted in ascending order. Equal elements are paired one to one,
+    /// so sets should be deduplicated first, for example with
+    /// [`unique_sorted`](IterExtd::unique_sorted).
     ///
     /// # Panics
     ///
-    /// Panics if the step size is zero.
+    /// Panics in debug mode if an unsorted element is encountered.
     ///
     /// # Examples
     ///
@@ -417,20 +519,22 @@ pub trait IterExtd: Iterator {
     /// ```
     /// use iterextd::IterExtd;
     ///
-    /// let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
-    /// let iter = arr.into_iter().inclusive_step_by(4);
-    /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![0, 4, 8, 9]);
+    /// let arr = [1, 3, 5, 7, 9];
+    /// let vec = arr.into_iter().difference_sorted([2, 3, 4, 9]).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![1, 5, 7]);
     /// ```
-    fn inclusive_step_by(self, step: usize) -> InclusiveStepBy<Self>
+    fn difference_sorted<J>(self, other: J) -> SetOpSorted<Self, J::IntoIter>
     where
         Self: Sized,
+        J: IntoIterator<Item = Self::Item>,
+        Self::Item: Ord,
     {
-        assert!(step != 0);
-        InclusiveStepBy::new(self, step)
+        SetOpSorted::new(self, other.into_iter(), SetOp::Difference)
     }
 
-    /// Creates an iterator that yields two elements per iteration.
+    /// Expands inclusive ranges into the integers they contain.
+    ///
+    /// This is the inverse of [`consecutive_ranges`](IterExtd::consecutive_ranges).
     ///
     /// # Examples
     ///
@@ -439,20 +543,24 @@ pub trait IterExtd: Iterator {
     /// ```
     /// use iterextd::IterExtd;
     ///
-    /// let arr = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-    /// let iter = arr.iter().map_by_two(|a, b| { Into::into(*a + *b) });
-    /// let vec = iter.collect::<Vec<u32>>();
-    /// assert_eq!(vec, vec![3, 7, 11, 15, 19]);
+    /// let arr = [1..=3, 5..=5, 7..=9];
+    /// let vec = arr.into_iter().expand_ranges().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![1, 2, 3, 5, 7, 8, 9]);
+    ///
+    /// let vec = (0..3).map(|x| x * 5).map(|x| x..=x + 1).expand_ranges().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 1, 5, 6, 10, 11]);
     /// ```
-    fn map_by_two<B, F>(self, f: F) -> MapByTwo<Self, F>
+    fn expand_ranges<T>(self) -> ExpandRanges<Self>
     where
-        F: FnMut(Self::Item, Self::Item) -> B,
-        Self: Sized,
+        Self: Sized + Iterator<Item = RangeInclusive<T>>,
+        T: PrimInt,
     {
-        MapByTwo::new(self, f)
+        ExpandRanges::new(self)
     }
 
-    /// Creates an iterator that yields three elements per iteration.
+    /// Repeats each element by the length of its run.
+    ///
+    /// This is the inverse of [`runs`](IterExtd::runs).
     ///
     /// # Examples
     ///
@@ -461,20 +569,23 @@ pub trait IterExtd: Iterator {
     /// ```
     /// use iterextd::IterExtd;
     ///
-    /// let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
-    /// let iter = arr.into_iter().map_by_three(|a, b, c| { [c, a, b] } );
-    /// let vec = iter.flatten().collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![2, 0, 1, 5, 3, 4, 8, 6, 7, 11, 9, 10, 14, 12, 13]);
+    /// let arr = [('a', 2), ('b', 0), ('c', 3)];
+    /// let string = arr.into_iter().expand_runs().collect::<String>();
+    /// assert_eq!(string, "aaccc");
     /// ```
-    fn map_by_three<B, F>(self, f: F) -> MapByThree<Self, F>
+    fn expand_runs<T>(self) -> ExpandRuns<Self, T>
     where
-        F: FnMut(Self::Item, Self::Item, Self::Item) -> B,
-        Self: Sized,
+        Self: Sized + Iterator<Item = (T, usize)>,
+        T: Clone,
     {
-        MapByThree::new(self, f)
+        ExpandRuns::new(self)
     }
 
-    /// Creates an iterator that yields two iterators per iteration.
+    /// Extrapolates the iterator's elements.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic in debug mode if the extrapolated values cause a computation overflow.
     ///
     /// # Examples
     ///
@@ -483,137 +594,106 @@ pub trait IterExtd: Iterator {
     /// ```
     /// use iterextd::IterExtd;
     ///
-    /// let self_arr: [u16; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-    /// let oth_arr: [&u8; 5] = [&20, &21, &22, &23, &24];
-    /// let self_iter = self_arr.iter();
-    /// let other_iter = oth_arr.iter();
-    /// let iter = self_iter
-    ///     .map_iters(
-    ///         other_iter.clone(), |s, o| { Some([*s.next()?, *s.next()?, *s.next()?, (*(*o.next()?)).into()]) }
-    ///     );
-    /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![[1, 2, 3, 20], [4, 5, 6, 21], [7, 8, 9, 22]]);
+    /// let arr: [f32; 2] = [0.00, 0.01];
+    /// let extrapolated = arr.into_iter().extrapolate().take(5).collect::<Vec<_>>();
+    /// assert_eq!(extrapolated, vec![0.0, 0.01, 0.02, 0.03, 0.04]);
+    ///
+    /// let vec = vec![2, 5, 6, 9, 13];
+    /// let extrapolated: Vec<_> = vec.into_iter().extrapolate().take(10).collect();
+    /// assert_eq!(extrapolated, vec![2, 5, 6, 9, 13, 17, 21, 25, 29, 33]);
     /// ```
-    fn map_iters<K, F, B>(self, k: K, f: F) -> MapIters<Self, K, F>
+    fn extrapolate(self) -> Extrapolate<Self>
     where
-        K: Iterator,
-        F: FnMut(&mut Self, &mut K) -> Option<B>,
         Self: Sized,
+        Self::Item: Zero,
     {
-        MapIters::new(self, k, f)
+        Extrapolate {
+            iter: self,
+            arg_one: Self::Item::zero(),
+            arg_two: Self::Item::zero(),
+        }
     }
 
-    /// Return an iterator adapter that yields missing integers.
-    /// Note that the maximum value of an iterator element must be less than or equal to
-    /// `usize::MAX` for unsigned type and `isize::MAX for signed`!
+    /// Extrapolates the iterator's elements with the selected mode.
+    ///
+    /// After the source iterator is exhausted, the adapter keeps generating elements from
+    /// the last `N` elements. [`Extrapolation::Polynomial`] uses all of them, a polynomial of
+    /// degree `N - 1`; the other modes use only the last one or two. While fewer elements
+    /// have been seen, the lower degree is used. An empty source iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is smaller than the number of elements required by the mode.
+    ///
+    /// This method will panic in debug mode if the extrapolated values cause a computation overflow.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// use iterextd::IterExtd;
+    /// use iterextd::{Extrapolation, IterExtd};
     ///
-    /// let arr = [9u8, 5, 6, 4, 8, 8, 2, 4, 10, 2, 12];
-    /// let vec = arr.iter().missing_integers().collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![3, 7, 11]);
+    /// let arr = [1, 4, 9, 16];
+    /// let iter = arr.into_iter().extrapolate_with::<3>(Extrapolation::Polynomial);
+    /// assert_eq!(iter.take(7).collect::<Vec<_>>(), vec![1, 4, 9, 16, 25, 36, 49]);
+    ///
+    /// let arr = [1.0, 1.5];
+    /// let iter = arr.into_iter().extrapolate_with::<2>(Extrapolation::Geometric);
+    /// assert_eq!(iter.take(4).collect::<Vec<_>>(), vec![1.0, 1.5, 2.25, 3.375]);
     /// ```
-    fn missing_integers(mut self) -> MissingIntegers<Self>
+    fn extrapolate_with<const N: usize>(self, mode: Extrapolation) -> ExtrapolateWith<Self, N>
     where
-        Self: Iterator + Sized + Clone,
-        <Self as Iterator>::Item: PartialOrd + Deref,
-        <<Self as Iterator>::Item as Deref>::Target: Copy,
-        usize: TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>,
-    {
-        let (min, size) = match self.clone().minmax() {
-            MinMax(min, max) => {
-                let min_max = (<usize as TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>>::try_from_by_add(*min),
-                    <usize as TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>>::try_from_by_add(*max));
-                match min_max {
-                    (Some(min), Some(max)) => (min, max - min + 1),
-                    _ => return MissingIntegers::default(),
-                }
-            }
-            _ => return MissingIntegers::default(),
-        };
-
-        let mut bitset = FixedBitSet::with_capacity(size);
-        bitset.toggle_range(..);
-
-        match self.try_for_each(|x| {
-            if let Some(val) = <usize as TryFromByAdd<
-                <<Self as Iterator>::Item as Deref>::Target,
-            >>::try_from_by_add(*x)
-            {
-                bitset.remove(val - min);
-                Some(())
-            } else {
-                None
-            }
-        }) {
-            Some(_) => MissingIntegers {
-                iter: bitset.into_ones(),
-                min,
-                _phantom: PhantomData,
-            },
-            _ => MissingIntegers::default(),
-        }
+        Self: Sized,
+        Self::Item: Zero,
+    {
+        ExtrapolateWith::new(self, mode)
     }
 
-    /// Returns an iterator adapter that finds missing integers.
-    /// This adapter must use a unique and sorted iterator.
+    /// Extrapolates the iterator's elements with the selected mode,
+    /// generating at most `count` elements after the source iterator is exhausted.
+    ///
+    /// See [`extrapolate_with`](IterExtd::extrapolate_with) for the modes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is smaller than the number of elements required by the mode.
+    ///
+    /// This method will panic in debug mode if the extrapolated values cause a computation overflow.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// use iterextd::IterExtd;
+    /// use iterextd::{Extrapolation, IterExtd};
     ///
-    /// let mut vec = vec![-2i8, 2, 4, 5, 6, 8, 9, 10, 12];
-    /// let mut iter = vec.iter().missing_integers_uqsort();
-    /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![-1, 0, 1, 3, 7, 11]);
+    /// let arr = [2u32, 4, 6];
+    /// let iter = arr.into_iter().extrapolate_n::<2>(Extrapolation::Linear, 3);
+    /// assert_eq!(iter.len(), 6);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 4, 6, 8, 10, 12]);
+    ///
+    /// let iter = arr.into_iter().extrapolate_n::<1>(Extrapolation::Constant, 2);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 4, 6, 6, 6]);
     /// ```
-    fn missing_integers_uqsort(
+    fn extrapolate_n<const N: usize>(
         self,
-    ) -> impl Iterator<Item = <Range<<<Self as Iterator>::Item as Deref>::Target> as Iterator>::Item>
-           + Debug
-    where
-        Self: Iterator + Sized + Clone + Debug,
-        <Self as Iterator>::Item: PartialOrd + Deref + Debug,
-        <<Self as Iterator>::Item as Deref>::Target:
-            Copy + Debug + ToZero<<<Self as Iterator>::Item as Deref>::Target>,
-        Range<<<Self as Iterator>::Item as Deref>::Target>: Iterator,
-        <Range<<<Self as Iterator>::Item as Deref>::Target> as Iterator>::Item:
-            PartialOrd<<<Self as Iterator>::Item as Deref>::Target>,
-    {
-        let (min, max) = match self.clone().minmax() {
-            MinMax(min, max) => (*min, *max),
-            _ => (
-                <<Self as Iterator>::Item as Deref>::Target::to_zero(),
-                <<Self as Iterator>::Item as Deref>::Target::to_zero(),
-            ),
-        };
-
-        (min..max).map_iters(self.peekable(), |range_it, seq_it| {
-            for range_val in range_it.by_ref() {
-                if range_val >= **seq_it.peek()? {
-                    seq_it.next();
-                    continue;
-                } else {
-                    return Some(range_val);
-                };
-            }
-            None
-        })
+        mode: Extrapolation,
+        count: usize,
+    ) -> ExtrapolateN<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Zero,
+    {
+        ExtrapolateN::new(ExtrapolateWith::new(self, mode), count)
     }
 
-    /// Finds the mode(s) of the iterator's elements.
-    ///
-    /// # Warning
+    /// Finds the greatest common divisor (GCD) of the elements in the iterator together with
+    /// the Bézout coefficients.
     ///
-    /// The results are stored in a `HashMap`, which does not preserve the order of the elements.
+    /// Returns the non-negative GCD and one coefficient per element, such that the sum of
+    /// the elements multiplied by their coefficients equals the GCD. Returns `None` if the
+    /// iterator is empty or the GCD or a coefficient overflows the element type.
     ///
     /// # Examples
     ///
@@ -622,53 +702,897 @@ pub trait IterExtd: Iterator {
     /// ```
     /// use iterextd::IterExtd;
     ///
-    /// let vec = vec![1, 2, 2, 3, 3, 3, 3];
-    /// let modes = vec.into_iter().modes().collect::<Vec<_>>();
-    /// assert_eq!(modes, vec![(3, 4)]);
+    /// let (gcd, coeffs) = [240, 46].into_iter().extended_gcd().unwrap();
+    /// assert_eq!((gcd, coeffs.as_slice()), (2, [-9, 47].as_slice()));
+    ///
+    /// let arr = [6, 10, 15];
+    /// let (gcd, coeffs) = arr.into_iter().extended_gcd().unwrap();
+    /// let sum = arr.iter().zip(&coeffs).map(|(a, c)| a * c).sum::<i32>();
+    /// assert_eq!((gcd, sum), (1, 1));
+    ///
+    /// assert_eq!([i8::MIN, 0].into_iter().extended_gcd(), None);
     /// ```
-    #[cfg(feature = "std")]
-    fn modes(self) -> impl Iterator<Item = (Self::Item, usize)> + Debug
+    #[cfg(feature = "alloc")]
+    fn extended_gcd(mut self) -> Option<(Self::Item, Vec<Self::Item>)>
     where
         Self: Sized,
-        Self::Item: Eq + Hash + Debug,
+        Self::Item: Integer + Signed + CheckedMul + CheckedRem + CheckedSub + Copy,
     {
-        let mut hash_map = HashMap::new();
-        let mut max: usize = 0;
+        let first = self.next()?;
+        let second = self.next();
+        let (mut acc, x, y) = checked_extended_gcd(first, second.unwrap_or_else(Self::Item::zero))?;
+        let mut coeffs = Vec::from([x]);
+        coeffs.extend(second.map(|_| y));
         for item in self {
-            let count = hash_map.entry(item).or_insert(0);
-            *count += 1;
-            if *count > max {
-                max = *count;
+            let (gcd, x, y) = checked_extended_gcd(acc, item)?;
+            for coeff in coeffs.iter_mut() {
+                *coeff = coeff.checked_mul(&x)?;
             }
+            coeffs.push(y);
+            acc = gcd;
         }
 
-        hash_map.into_iter().filter(move |&(_, count)| count == max)
+        Some((acc, coeffs))
     }
 
-    /// The iterator adapter adds an offset to a two-element tuple.
+    /// Finds the greatest common divisor (GCD) of the elements in the iterator.
     ///
-    /// # Warning
+    /// # Notes
     ///
-    /// When using this iterator adapter, the output value may overflow.
+    /// - **NOTE:** This method will return `None` if the iterator is empty.
+    /// - **NOTE:** The minimum sequence length must be two elements; otherwise, `None` will be returned.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// use iterextd::{CircleBresenhamSeq, IterExtd};
+    /// use iterextd::IterExtd;
     ///
-    /// let radius = 1_u8;
-    /// let center_x = radius as i32;
-    /// let center_y = radius as i32;
-    /// let iter = CircleBresenhamSeq::<i32>::new(radius);
-    /// let circle_with_offset = iter.offset(center_x, center_y);
-    /// let vec = circle_with_offset.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(1, 0), (2, 1), (1, 2), (0, 1)]);
-    /// ```
-    #[inline]
-    fn offset<T>(self, offset_x: T, offset_y: T) -> Offset<T, Self>
-    where
+    /// let vec = vec![24, 36, 48];
+    /// assert_eq!(vec.iter().gcd(), Some(12));
+    ///
+    /// let empty_vec: Vec<i32> = vec![];
+    /// assert_eq!(empty_vec.iter().gcd(), None);
+    ///
+    /// let single_element = vec![42];
+    /// assert_eq!(single_element.iter().gcd(), None);
+    /// ```
+    fn gcd(mut self) -> Option<<<Self as Iterator>::Item as Deref>::Target>
+    where
+        Self: Sized,
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: Integer + Copy,
+    {
+        let acc = gcd(*self.next()?, *self.next()?);
+        Some(self.fold(acc, |acc, x| gcd(acc, *x)))
+    }
+
+    /// Finds the greatest common divisor (GCD) of the elements in the iterator by value.
+    ///
+    /// Unlike [`gcd`](IterExtd::gcd), the elements are taken by value and the GCD of a single
+    /// element is its absolute value. Returns `None` if the iterator is empty or the GCD is the
+    /// absolute value of the minimum of a signed type, which is not representable.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// assert_eq!([24, 36, 48].into_iter().gcd_by_value(), Some(12));
+    /// assert_eq!([-42].into_iter().gcd_by_value(), Some(42));
+    /// assert_eq!([i8::MIN, 0].into_iter().gcd_by_value(), None);
+    /// assert_eq!([0_u8; 0].into_iter().gcd_by_value(), None);
+    /// ```
+    fn gcd_by_value(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Integer + CheckedSub + Copy,
+    {
+        let first = self.next()?;
+        let acc = checked_gcd(first, self.next().unwrap_or_else(Self::Item::zero))?;
+        self.try_fold(acc, checked_gcd)
+    }
+
+    /// Finds the least common multiple (LCM) of the elements in the iterator.
+    ///
+    /// The LCM is non-negative, and zero if any element is zero. Returns `None` if the iterator
+    /// is empty or the LCM overflows the element type.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// assert_eq!([4, -6, 10].into_iter().lcm(), Some(60));
+    /// assert_eq!([16_u8, 17].into_iter().lcm(), None);
+    /// assert_eq!([0, i8::MIN].into_iter().lcm(), Some(0));
+    /// assert_eq!([2, i8::MIN].into_iter().lcm(), None);
+    /// assert_eq!([0_i32; 0].into_iter().lcm(), None);
+    /// ```
+    fn lcm(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Integer + CheckedMul + CheckedSub + Copy,
+    {
+        let abs = |value: Self::Item| {
+            if value < Self::Item::zero() {
+                Self::Item::zero().checked_sub(&value)
+            } else {
+                Some(value)
+            }
+        };
+
+        let mut acc = abs(self.next()?);
+        for item in self {
+            if item.is_zero() {
+                return Some(item);
+            }
+            acc = acc.and_then(|acc| {
+                if acc.is_zero() {
+                    return Some(acc);
+                }
+                abs((item / checked_gcd(acc, item)?).checked_mul(&acc)?)
+            });
+        }
+
+        acc
+    }
+
+    /// Create an indexes iterator with a start and end for each step.
+    ///
+    /// With each iteration, it furnishes the start and end indices of the current step,
+    /// while also accounting for any residual elements in the last incomplete step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step size is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let s = "slice index iterator";
+    /// let iter = (0..s.len()).step_boundary(3);
+    /// iter.for_each(|bounds| { println!("{:?}", &s[bounds.0..=bounds.1] ); });
+    /// ```
+    fn step_boundary(self, size: usize) -> StepBoundary<Self>
+    where
+        Self: Sized,
+    {
+        assert!(size != 0);
+        StepBoundary::new(self, size)
+    }
+
+    /// Creates an iterator that performs the given step at each iteration, returning inclusive of the first and last element.
+    ///
+    /// If there are elements smaller than the remaining step, the last element will be returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step size is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let iter = arr.into_iter().inclusive_step_by(4);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 4, 8, 9]);
+    /// ```
+    fn inclusive_step_by(self, step: usize) -> InclusiveStepBy<Self>
+    where
+        Self: Sized,
+    {
+        assert!(step != 0);
+        InclusiveStepBy::new(self, step)
+    }
+
+    /// Inserts `count` evenly spaced values between every pair of consecutive elements.
+    ///
+    /// For integer types the offset from the preceding element is truncated towards it,
+    /// see [`interpolate_rounded`](IterExtd::interpolate_rounded) for other rounding modes.
+    /// Integer values are computed without intermediate overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [0.0, 1.0, 0.0];
+    /// let iter = arr.into_iter().interpolate(3);
+    /// assert_eq!(iter.len(), 9);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0.0, 0.25, 0.5, 0.75, 1.0, 0.75, 0.5, 0.25, 0.0]);
+    ///
+    /// let arr = [10u8, 20, 15];
+    /// let vec = arr.into_iter().interpolate(1).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![10, 15, 20, 18, 15]);
+    /// ```
+    fn interpolate(self, count: usize) -> Interpolate<Self>
+    where
+        Self: Sized,
+        Self::Item: Copy
+            + PartialOrd
+            + NumCast
+            + Add<Output = Self::Item>
+            + Sub<Output = Self::Item>
+            + Mul<Output = Self::Item>
+            + Div<Output = Self::Item>,
+    {
+        Interpolate::new(self, count, None)
+    }
+
+    /// Inserts `count` evenly spaced integers between every pair of consecutive elements,
+    /// rounding the inserted values with the given mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtd, Rounding};
+    ///
+    /// let arr = [0, 10, 0];
+    /// let vec = arr.into_iter().interpolate_rounded(2, Rounding::Nearest).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 3, 7, 10, 7, 3, 0]);
+    ///
+    /// let vec = arr.into_iter().interpolate_rounded(2, Rounding::Ceil).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 4, 7, 10, 7, 4, 0]);
+    /// ```
+    fn interpolate_rounded(self, count: usize, rounding: Rounding) -> Interpolate<Self>
+    where
+        Self: Sized,
+        Self::Item: Copy + Integer + NumCast,
+    {
+        Interpolate::new(self, count, Some(rounding))
+    }
+
+    /// Creates an iterator of the sorted elements that are also in the other sorted iterator.
+    ///
+    /// Both iterators must be sorted in ascending order. Equal elements are paired one to one,
+    /// so sets should be deduplicated first, for example with
+    /// [`unique_sorted`](IterExtd::unique_sorted).
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug mode if an unsorted element is encountered.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [1, 3, 5, 7, 9];
+    /// let vec = arr.into_iter().intersect_sorted([2, 3, 4, 9]).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![3, 9]);
+    /// ```
+    fn intersect_sorted<J>(self, other: J) -> SetOpSorted<Self, J::IntoIter>
+    where
+        Self: Sized,
+        J: IntoIterator<Item = Self::Item>,
+        Self::Item: Ord,
+    {
+        SetOpSorted::new(self, other.into_iter(), SetOp::Intersection)
+    }
+
+    /// Creates an iterator that passes N elements per iteration to the closure as an array.
+    ///
+    /// The trailing elements that do not fill a whole array are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if N is 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let iter = arr.iter().map_by::<4, _, _>(|[a, b, c, d]| a + b + c + d);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![10, 26]);
+    /// ```
+    fn map_by<const N: usize, B, F>(self, f: F) -> MapBy<Self, F, N>
+    where
+        F: FnMut([Self::Item; N]) -> B,
+        Self: Sized,
+    {
+        MapBy::new(self.arr_chunks(), f)
+    }
+
+    /// Creates an iterator that passes N elements per iteration to the closure as an array,
+    /// padding the trailing partial array with clones of `pad`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if N is 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let iter = arr.into_iter().map_by_pad::<4, _, _>(0, |[a, b, c, d]| a + b + c + d);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![10, 26, 19]);
+    /// ```
+    fn map_by_pad<const N: usize, B, F>(self, pad: Self::Item, f: F) -> MapByPad<Self, F, N>
+    where
+        F: FnMut([Self::Item; N]) -> B,
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        MapByPad::new(self.arr_chunks(), pad, f)
+    }
+
+    /// Creates an iterator that passes N elements per iteration to the closure as an array,
+    /// passing the trailing partial array to the second closure as a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if N is 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let iter = arr.into_iter().map_by_rest::<4, _, _, _>(
+    ///     |arr| arr.iter().sum::<u8>(),
+    ///     |rest| rest.iter().sum::<u8>() * 10,
+    /// );
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![10, 26, 190]);
+    /// ```
+    fn map_by_rest<const N: usize, B, F, G>(self, f: F, g: G) -> MapByRest<Self, F, G, N>
+    where
+        F: FnMut([Self::Item; N]) -> B,
+        G: FnMut(&[Self::Item]) -> B,
+        Self: Sized,
+    {
+        MapByRest::new(self.arr_chunks(), f, g)
+    }
+
+    /// Creates an iterator that yields two elements per iteration.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let iter = arr.iter().map_by_two(|a, b| { Into::into(*a + *b) });
+    /// let vec = iter.collect::<Vec<u32>>();
+    /// assert_eq!(vec, vec![3, 7, 11, 15, 19]);
+    /// ```
+    fn map_by_two<B, F>(self, f: F) -> MapByTwo<Self, F>
+    where
+        F: FnMut(Self::Item, Self::Item) -> B,
+        Self: Sized,
+    {
+        MapByTwo::new(self, f)
+    }
+
+    /// Creates an iterator that yields three elements per iteration.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+    /// let iter = arr.into_iter().map_by_three(|a, b, c| { [c, a, b] } );
+    /// let vec = iter.flatten().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![2, 0, 1, 5, 3, 4, 8, 6, 7, 11, 9, 10, 14, 12, 13]);
+    /// ```
+    fn map_by_three<B, F>(self, f: F) -> MapByThree<Self, F>
+    where
+        F: FnMut(Self::Item, Self::Item, Self::Item) -> B,
+        Self: Sized,
+    {
+        MapByThree::new(self, f)
+    }
+
+    /// Creates an iterator that yields two iterators per iteration.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let self_arr: [u16; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let oth_arr: [&u8; 5] = [&20, &21, &22, &23, &24];
+    /// let self_iter = self_arr.iter();
+    /// let other_iter = oth_arr.iter();
+    /// let iter = self_iter
+    ///     .map_iters(
+    ///         other_iter.clone(), |s, o| { Some([*s.next()?, *s.next()?, *s.next()?, (*(*o.next()?)).into()]) }
+    ///     );
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![[1, 2, 3, 20], [4, 5, 6, 21], [7, 8, 9, 22]]);
+    /// ```
+    fn map_iters<K, F, B>(self, k: K, f: F) -> MapIters<Self, K, F>
+    where
+        K: Iterator,
+        F: FnMut(&mut Self, &mut K) -> Option<B>,
+        Self: Sized,
+    {
+        MapIters::new(self, k, f)
+    }
+
+    /// Finds the median of the numeric elements in the iterator.
+    ///
+    /// Same as [`percentile(50.0)`](IterExtd::percentile).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// assert_eq!([5, 1, 4, 2].into_iter().median(), Some(3.0));
+    /// assert_eq!([5.0, 1.0, 4.0].into_iter().median(), Some(4.0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn median(self) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: NumCast,
+    {
+        self.percentile(50.0)
+    }
+
+    /// Return an iterator adapter that yields missing integers.
+    ///
+    /// The integers are sorted and deduplicated as in [`unique_sorted`](IterExtd::unique_sorted),
+    /// so any range of a primitive integer type is supported.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [9u8, 5, 6, 4, 8, 8, 2, 4, 10, 2, 12];
+    /// let vec = arr.iter().missing_integers().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![3, 7, 11]);
+    ///
+    /// let arr = [-2i128, 2, -5, i128::MAX];
+    /// let vec = arr.iter().missing_integers().take(6).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![-4, -3, -1, 0, 1, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn missing_integers(self) -> MissingIntegers<MissingRanges<UniqueSorted<Self>>>
+    where
+        Self: Iterator + Sized + Clone,
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: PrimInt + TryFromByAdd<u128>,
+        u128: TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>,
+    {
+        MissingIntegers::new(MissingRanges::new(self.unique_sorted()))
+    }
+
+    /// Return an iterator adapter that yields the ranges of missing integers.
+    ///
+    /// This is the collapsed form of [`missing_integers`](IterExtd::missing_integers),
+    /// suitable for long runs of missing integers.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [u128::MAX, 9, 5, 6, 4, 8, 8, 2, 4, 10, 2, 12];
+    /// let vec = arr.iter().missing_ranges().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![3..=3, 7..=7, 11..=11, 13..=u128::MAX - 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn missing_ranges(self) -> MissingRanges<UniqueSorted<Self>>
+    where
+        Self: Iterator + Sized + Clone,
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: PrimInt + TryFromByAdd<u128>,
+        u128: TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>,
+    {
+        MissingRanges::new(self.unique_sorted())
+    }
+
+    /// Return an iterator adapter that yields the integers of the range that are missing
+    /// from the iterator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [3u8, 9, 5, 6, 4, 8, 8, 4, 3];
+    /// let vec = arr.iter().missing_integers_in(0..=10).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 1, 2, 7, 10]);
+    ///
+    /// let vec = arr.iter().missing_integers_in(4..8).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![7]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn missing_integers_in<R>(
+        self,
+        range: R,
+    ) -> MissingIntegers<MissingRangesIn<UniqueSorted<Self>>>
+    where
+        Self: Iterator + Sized + Clone,
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: PrimInt + TryFromByAdd<u128>,
+        u128: TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>,
+        R: RangeBounds<<<Self as Iterator>::Item as Deref>::Target>,
+    {
+        MissingIntegers::new(self.missing_ranges_in(range))
+    }
+
+    /// Return an iterator adapter that yields the ranges of integers of the given range
+    /// that are missing from the iterator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [3u64, 9, 5, 6, 4, 8, 8, 4, 3];
+    /// let vec = arr.iter().missing_ranges_in(..).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0..=2, 7..=7, 10..=u64::MAX]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn missing_ranges_in<R>(self, range: R) -> MissingRangesIn<UniqueSorted<Self>>
+    where
+        Self: Iterator + Sized + Clone,
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: PrimInt + TryFromByAdd<u128>,
+        u128: TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>,
+        R: RangeBounds<<<Self as Iterator>::Item as Deref>::Target>,
+    {
+        MissingRangesIn::new(self.unique_sorted(), range)
+    }
+
+    /// Returns an iterator adapter that finds missing integers.
+    /// This adapter must use a unique and sorted iterator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let mut vec = vec![-2i8, 2, 4, 5, 6, 8, 9, 10, 12];
+    /// let mut iter = vec.iter().missing_integers_uqsort();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![-1, 0, 1, 3, 7, 11]);
+    /// ```
+    fn missing_integers_uqsort(
+        self,
+    ) -> impl Iterator<Item = <<Self as Iterator>::Item as Deref>::Target> + Debug
+    where
+        Self: Iterator + Sized + Debug,
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: PrimInt + Debug,
+    {
+        MissingIntegers::new(MissingRanges::new(self.map(|x| *x)))
+    }
+
+    /// Returns an iterator adapter that finds the ranges of missing integers.
+    /// This adapter must use a unique and sorted iterator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let mut vec = vec![i64::MIN, -2, 2, 4, 5, 6, 8, 9, 10, 12];
+    /// let mut iter = vec.iter().missing_ranges_uqsort();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![i64::MIN + 1..=-3, -1..=1, 3..=3, 7..=7, 11..=11]);
+    /// ```
+    fn missing_ranges_uqsort(
+        self,
+    ) -> impl Iterator<Item = RangeInclusive<<<Self as Iterator>::Item as Deref>::Target>> + Debug
+    where
+        Self: Iterator + Sized + Debug,
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: PrimInt + Debug,
+    {
+        MissingRanges::new(self.map(|x| *x))
+    }
+
+    /// Finds the mode(s) of the iterator's elements.
+    ///
+    /// # Warning
+    ///
+    /// The results are stored in a `HashMap`, which does not preserve the order of the elements.
+    /// Use [`modes_sorted`](IterExtd::modes_sorted) for a deterministic order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let vec = vec![1, 2, 2, 3, 3, 3, 3];
+    /// let modes = vec.into_iter().modes().collect::<Vec<_>>();
+    /// assert_eq!(modes, vec![(3, 4)]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn modes(self) -> impl Iterator<Item = (Self::Item, usize)> + Debug
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash + Debug,
+    {
+        let mut hash_map = HashMap::new();
+        let mut max: usize = 0;
+        for item in self {
+            let count = hash_map.entry(item).or_insert(0);
+            *count += 1;
+            if *count > max {
+                max = *count;
+            }
+        }
+
+        hash_map.into_iter().filter(move |&(_, count)| count == max)
+    }
+
+    /// Finds the mode(s) of the iterator's elements in ascending order.
+    ///
+    /// The counts are stored in a `BTreeMap`, so `std` is not required.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let vec = vec![3, 1, 2, 2, 3, 1, 3, 1];
+    /// let modes = vec.into_iter().modes_sorted().collect::<Vec<_>>();
+    /// assert_eq!(modes, vec![(1, 3), (3, 3)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn modes_sorted(self) -> impl Iterator<Item = (Self::Item, usize)> + Debug
+    where
+        Self: Sized,
+        Self::Item: Ord + Debug,
+    {
+        let mut btree_map = BTreeMap::new();
+        let mut max: usize = 0;
+        for item in self {
+            let count = btree_map.entry(item).or_insert(0);
+            *count += 1;
+            max = max.max(*count);
+        }
+
+        btree_map
+            .into_iter()
+            .filter(move |&(_, count)| count == max)
+    }
+
+    /// Finds the least common element(s) of the iterator in ascending order.
+    ///
+    /// The counts are stored in a `BTreeMap`, so `std` is not required.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let vec = vec![3, 1, 2, 2, 3, 1, 3, 4];
+    /// let least = vec.into_iter().least_common().collect::<Vec<_>>();
+    /// assert_eq!(least, vec![(4, 1)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn least_common(self) -> impl Iterator<Item = (Self::Item, usize)> + Debug
+    where
+        Self: Sized,
+        Self::Item: Ord + Debug,
+    {
+        let freqs = self.count_freq_sorted(FreqOrder::Key).collect::<Vec<_>>();
+        let min = freqs.iter().map(|&(_, count)| count).min().unwrap_or(0);
+
+        freqs.into_iter().filter(move |&(_, count)| count == min)
+    }
+
+    /// Returns the `k` most frequent elements of the iterator with their counts.
+    ///
+    /// The elements are yielded in descending order of the counts, equal counts in ascending
+    /// order of the elements. The counts are stored in a `BTreeMap`, so `std` is not required.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let top = "abracadabra".chars().top_k(2).collect::<Vec<_>>();
+    /// assert_eq!(top, vec![('a', 5), ('b', 2)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn top_k(self, k: usize) -> impl Iterator<Item = (Self::Item, usize)> + Debug
+    where
+        Self: Sized,
+        Self::Item: Ord + Debug,
+    {
+        self.count_freq_sorted(FreqOrder::Count).take(k)
+    }
+
+    /// Counts the numeric elements of the iterator in `bins` bins of equal width over `min..=max`.
+    ///
+    /// Yields the bounds of each bin with the number of elements in it. A bin includes its lower
+    /// bound, the last bin also includes the maximum. The bounds are computed in the type of the
+    /// elements, integer bounds are rounded up so that each bin holds exactly the integers within
+    /// its bounds. Integer bins are at least one wide, so fewer bins are yielded if `min..=max`
+    /// holds fewer than `bins` integers. NaN and infinite values are ignored, an iterator without
+    /// finite values yields no bins.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bins` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    /// use std::ops::{Bound::{Excluded, Included}, RangeBounds};
+    ///
+    /// let arr = [0, 1, 2, 2, 5, 7, 8];
+    /// let hist = arr.into_iter().histogram(4).collect::<Vec<_>>();
+    /// assert_eq!(hist, vec![
+    ///     ((Included(0), Excluded(2)), 2),
+    ///     ((Included(2), Excluded(4)), 2),
+    ///     ((Included(4), Excluded(6)), 1),
+    ///     ((Included(6), Included(8)), 2),
+    /// ]);
+    /// assert!(hist[3].0.contains(&8));
+    ///
+    /// let hist = [0_u8, 1, 2].into_iter().histogram(5).collect::<Vec<_>>();
+    /// assert_eq!(hist.len(), 3);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn histogram(
+        self,
+        mut bins: usize,
+    ) -> impl Iterator<Item = (HistogramBin<Self::Item>, usize)> + Debug
+    where
+        Self: Sized,
+        Self::Item: Copy
+            + PartialOrd
+            + NumCast
+            + Add<Output = Self::Item>
+            + Sub<Output = Self::Item>
+            + Mul<Output = Self::Item>
+            + Div<Output = Self::Item>,
+    {
+        assert!(bins != 0, "the number of bins must be non-zero");
+        let values = self
+            .filter(|&value| <f64 as NumCast>::from(value).is_some_and(f64::is_finite))
+            .collect::<Vec<_>>();
+        let min_max = values.iter().fold(None, |min_max, &value| match min_max {
+            None => Some((value, value)),
+            Some((min, max)) => Some((
+                if value < min { value } else { min },
+                if value > max { value } else { max },
+            )),
+        });
+
+        // The lower bound of each bin followed by the maximum.
+        let mut edges = Vec::new();
+        let mut counts = Vec::new();
+        if let Some((min, max)) = min_max {
+            if !is_fractional::<Self::Item>() {
+                let span = match (<u128 as NumCast>::from(min), <u128 as NumCast>::from(max)) {
+                    (Some(min), Some(max)) => max - min,
+                    _ => <i128 as NumCast>::from(min)
+                        .zip(<i128 as NumCast>::from(max))
+                        .map_or(u128::MAX, |(min, max)| max.abs_diff(min)),
+                };
+                bins = usize::try_from(span).map_or(bins, |span| bins.min(span.saturating_add(1)));
+            }
+            edges = (0..bins)
+                .map(|idx| interpolate_value(min, max, idx, bins, Some(Rounding::Ceil)))
+                .collect::<Vec<_>>();
+            edges.push(max);
+            counts.resize(bins, 0_usize);
+            // The bin of a value is the last one starting at or below it.
+            let indexes = values
+                .into_iter()
+                .map(|value| edges[..bins].partition_point(|&edge| edge <= value) - 1);
+            for (idx, count) in indexes.count_freq_sorted(FreqOrder::Key) {
+                counts[idx] = count;
+            }
+        }
+
+        counts.into_iter().enumerate().map(move |(idx, count)| {
+            let end = if idx + 1 == bins {
+                Bound::Included(edges[bins])
+            } else {
+                Bound::Excluded(edges[idx + 1])
+            };
+            ((Bound::Included(edges[idx]), end), count)
+        })
+    }
+
+    /// The iterator adapter adds an offset to a two-element tuple.
+    ///
+    /// # Warning
+    ///
+    /// When using this iterator adapter, the output value may overflow.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{CircleBresenhamSeq, IterExtd};
+    ///
+    /// let radius = 1_u8;
+    /// let center_x = radius as i32;
+    /// let center_y = radius as i32;
+    /// let iter = CircleBresenhamSeq::<i32>::new(radius);
+    /// let circle_with_offset = iter.offset(center_x, center_y);
+    /// let vec = circle_with_offset.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(1, 0), (2, 1), (1, 2), (0, 1)]);
+    /// ```
+    #[inline]
+    fn offset<T>(self, offset_x: T, offset_y: T) -> Offset<T, Self>
+    where
         Self: Sized + Iterator,
     {
         Offset {
@@ -678,6 +1602,57 @@ pub trait IterExtd: Iterator {
         }
     }
 
+    /// Finds the `p`-th percentile of the numeric elements in the iterator.
+    ///
+    /// The elements are converted to `f64` and the result is linearly interpolated between
+    /// the two closest ranks. The ranks are found by selection, without sorting all elements.
+    /// NaN values are ignored, `None` is returned if no other values remain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in the range `0.0..=100.0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [15, 20, 35, 40, 50];
+    /// assert_eq!(arr.into_iter().percentile(0.0), Some(15.0));
+    /// assert_eq!(arr.into_iter().percentile(40.0), Some(29.0));
+    /// assert_eq!(arr.into_iter().percentile(100.0), Some(50.0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn percentile(self, p: f64) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: NumCast,
+    {
+        assert!(
+            (0.0..=100.0).contains(&p),
+            "the percentile must be in the range 0.0..=100.0"
+        );
+        let mut values = self
+            .filter_map(<f64 as NumCast>::from)
+            .filter(|value| !value.is_nan())
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            return None;
+        }
+
+        let rank = p / 100.0 * (values.len() - 1) as f64;
+        let idx = rank as usize;
+        let fract = rank - idx as f64;
+        let (_, &mut lower, rest) = values.select_nth_unstable_by(idx, f64::total_cmp);
+        if fract == 0.0 {
+            return Some(lower);
+        }
+        let upper = rest.iter().copied().min_by(f64::total_cmp)?;
+        Some(lower + (upper - lower) * fract)
+    }
+
     /// The iterator adapter provides the ability to obtain a tuple of two values (last, current) at each iteration.
     ///
     /// # Examples
@@ -724,6 +1699,29 @@ pub trait IterExtd: Iterator {
         LastTaken::new(self, None)
     }
 
+    /// Yields consecutive equal elements once with the length of their run.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let vec = "aaabccdd".chars().runs().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![('a', 3), ('b', 1), ('c', 2), ('d', 2)]);
+    ///
+    /// let string = "aaabccdd".chars().runs().expand_runs().collect::<String>();
+    /// assert_eq!(string, "aaabccdd");
+    /// ```
+    fn runs(self) -> Runs<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        Runs::new(self)
+    }
+
     /// Creates an iterator that copies a slice of all its elements.
     ///
     /// # Panics
@@ -783,6 +1781,39 @@ pub trait IterExtd: Iterator {
         }
     }
 
+    /// Computes the count, sum, minimum, maximum, mean and variance of the numeric elements
+    /// in one pass.
+    ///
+    /// The sum is accumulated in a wider type, so it does not overflow the element type.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let stats = [2, 4, 4, 4, 5, 5, 7, 9].into_iter().stats();
+    /// assert_eq!(stats.count(), 8);
+    /// assert_eq!(stats.sum(), Some(40));
+    /// assert_eq!((stats.min(), stats.max()), (Some(2), Some(9)));
+    /// assert_eq!(stats.mean(), Some(5.0));
+    /// assert_eq!(stats.variance(), Some(4.0));
+    ///
+    /// let stats = [200_u8, 100].into_iter().stats();
+    /// assert_eq!(stats.sum(), None);
+    /// assert_eq!(stats.mean(), Some(150.0));
+    /// ```
+    fn stats(self) -> Stats<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Copy + PartialOrd + NumCast,
+    {
+        let mut stats = Stats::new();
+        stats.extend(self);
+        stats
+    }
+
     /// The iterator adapter passing through the base iterator uses a closure at each step to change the number of steps.
     ///
     /// # Examples
@@ -817,6 +1848,36 @@ pub trait IterExtd: Iterator {
         }
     }
 
+    /// Creates an iterator of the sorted elements that are in exactly one of the sorted iterators.
+    ///
+    /// Both iterators must be sorted in ascending order. Equal elements are paired one to one,
+    /// so sets should be deduplicated first, for example with
+    /// [`unique_sorted`](IterExtd::unique_sorted).
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug mode if an unsorted element is encountered.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [1, 3, 5, 7, 9];
+    /// let vec = arr.into_iter().symmetric_difference_sorted([2, 3, 4, 9]).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![1, 2, 4, 5, 7]);
+    /// ```
+    fn symmetric_difference_sorted<J>(self, other: J) -> SetOpSorted<Self, J::IntoIter>
+    where
+        Self: Sized,
+        J: IntoIterator<Item = Self::Item>,
+        Self::Item: Ord,
+    {
+        SetOpSorted::new(self, other.into_iter(), SetOp::SymmetricDifference)
+    }
+
     /// An iterator that takes `n` and skips `m` iterator elements cyclically.
     ///
     /// # Examples
@@ -928,8 +1989,198 @@ pub trait IterExtd: Iterator {
         RangeIcvToTup { iter: self }
     }
 
+    /// Create an array from an iterator, returning the collected elements instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`collect_array`](IterExtd::collect_array).
+    /// Only the first N elements are taken from the iterator.
+    ///
+    /// # Errors
+    ///
+    /// If the iterator yields fewer than N elements, returns a [`PartialArray`] holding the
+    /// elements collected so far. It can be converted into [`IterExtdError::TooShort`] with `?`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [1, 2, 3].into_iter().try_collect_array::<2>();
+    /// assert_eq!(arr.ok(), Some([1, 2]));
+    ///
+    /// let partial = [1, 2, 3].into_iter().try_collect_array::<4>().unwrap_err();
+    /// assert_eq!(partial.collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    fn try_collect_array<const N: usize>(
+        mut self,
+    ) -> Result<[<Self as Iterator>::Item; N], PartialArray<<Self as Iterator>::Item, N>>
+    where
+        Self: Sized + Iterator,
+    {
+        PartialArray::new().fill(&mut self)
+    }
+
+    /// Combine two iterators in parts sequentially, returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`combine_iters`](IterExtd::combine_iters).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::ZeroSize`] if both `self_part_len` and `other_part_len` are 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtd, IterExtdError};
+    ///
+    /// let res = (0..4).try_combine_iters(0, 10..14, 0);
+    /// assert_eq!(res.err().unwrap(), IterExtdError::ZeroSize);
+    ///
+    /// let vec = (0..4).try_combine_iters(2, 10..14, 1).unwrap().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 1, 10, 2, 3, 11]);
+    /// ```
+    fn try_combine_iters<J>(
+        self,
+        self_part_len: usize,
+        other_iter: J,
+        other_part_len: usize,
+    ) -> Result<CombineIters<Self, J>, IterExtdError>
+    where
+        J: Iterator,
+        Self: Sized,
+    {
+        if (self_part_len == 0) && (other_part_len == 0) {
+            return Err(IterExtdError::ZeroSize);
+        }
+        Ok(self.combine_iters(self_part_len, other_iter, other_part_len))
+    }
+
+    /// Creates an iterator that performs the given step at each iteration,
+    /// returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`inclusive_step_by`](IterExtd::inclusive_step_by).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::ZeroSize`] if the step size is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtd, IterExtdError};
+    ///
+    /// assert_eq!((0..10).try_inclusive_step_by(0).err(), Some(IterExtdError::ZeroSize));
+    ///
+    /// let vec = (0..10).try_inclusive_step_by(4).unwrap().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0, 4, 8, 9]);
+    /// ```
+    fn try_inclusive_step_by(self, step: usize) -> Result<InclusiveStepBy<Self>, IterExtdError>
+    where
+        Self: Sized,
+    {
+        if step == 0 {
+            return Err(IterExtdError::ZeroSize);
+        }
+        Ok(InclusiveStepBy::new(self, step))
+    }
+
+    /// Create an indexes iterator with a start and end for each step,
+    /// returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`step_boundary`](IterExtd::step_boundary).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::ZeroSize`] if the step size is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtd, IterExtdError};
+    ///
+    /// assert_eq!((0..10).try_step_boundary(0).err(), Some(IterExtdError::ZeroSize));
+    ///
+    /// let vec = (0..10).try_step_boundary(4).unwrap().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 3), (4, 7), (8, 9)]);
+    /// ```
+    fn try_step_boundary(self, size: usize) -> Result<StepBoundary<Self>, IterExtdError>
+    where
+        Self: Sized,
+    {
+        if size == 0 {
+            return Err(IterExtdError::ZeroSize);
+        }
+        Ok(StepBoundary::new(self, size))
+    }
+
+    /// Return an iterator adapter that yields unique sorted integers outside the given range.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [3i32, 12, 5, -6, 4, 8, 12, 4, 3];
+    /// let vec = arr.iter().unexpected_integers_in(0..10).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![-6, 12]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn unexpected_integers_in<R>(self, range: R) -> UnexpectedIntegersIn<UniqueSorted<Self>>
+    where
+        Self: Iterator + Sized + Clone,
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: PrimInt + TryFromByAdd<u128>,
+        u128: TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>,
+        R: RangeBounds<<<Self as Iterator>::Item as Deref>::Target>,
+    {
+        UnexpectedIntegersIn::new(self.unique_sorted(), range)
+    }
+
+    /// Creates an iterator of the sorted elements that are in either of the sorted iterators.
+    ///
+    /// Both iterators must be sorted in ascending order. Equal elements are paired one to one,
+    /// so sets should be deduplicated first, for example with
+    /// [`unique_sorted`](IterExtd::unique_sorted).
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug mode if an unsorted element is encountered.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let arr = [1, 3, 5, 7, 9];
+    /// let vec = arr.into_iter().union_sorted([2, 3, 4, 9]).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5, 7, 9]);
+    /// ```
+    fn union_sorted<J>(self, other: J) -> SetOpSorted<Self, J::IntoIter>
+    where
+        Self: Sized,
+        J: IntoIterator<Item = Self::Item>,
+        Self::Item: Ord,
+    {
+        SetOpSorted::new(self, other.into_iter(), SetOp::Union)
+    }
+
     /// Return an iterator adapter that yields unique sorted integers.
     ///
+    /// A bit set spanning the range of the integers is used when it takes no more memory
+    /// than the integers themselves, otherwise they are sorted and deduplicated.
+    /// The chosen strategy is reported by [`UniqueSorted::strategy`].
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -940,46 +2191,48 @@ pub trait IterExtd: Iterator {
     /// let arr = [9u8, 5, 6, 4, 8, 8, 2, 4, 10, 2, 12];
     /// let vec = arr.iter().unique_sorted().collect::<Vec<_>>();
     /// assert_eq!(vec, vec![2, 4, 5, 6, 8, 9, 10, 12]);
+    ///
+    /// let arr = [i128::MAX, -5, i128::MIN, -5];
+    /// let vec = arr.iter().unique_sorted().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![i128::MIN, -5, i128::MAX]);
     /// ```
-    fn unique_sorted(mut self) -> UniqueSorted<Self>
+    #[cfg(feature = "alloc")]
+    fn unique_sorted(self) -> UniqueSorted<Self>
     where
         Self: Iterator + Sized + Clone,
-        <Self as Iterator>::Item: PartialOrd + Deref,
-        <<Self as Iterator>::Item as Deref>::Target: Copy,
-        usize: TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>,
-    {
-        let (min, size) = match self.clone().minmax() {
-            MinMax(min, max) => {
-                let min_max = (<usize as TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>>::try_from_by_add(*min),
-                    <usize as TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>>::try_from_by_add(*max));
-                match min_max {
-                    (Some(min), Some(max)) => (min, max - min + 1),
-                    _ => return UniqueSorted::default(),
-                }
-            }
-            _ => return UniqueSorted::default(),
-        };
-
-        let mut bitset = FixedBitSet::with_capacity(size);
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: Copy + Ord,
+        u128: TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>,
+    {
+        UniqueSorted::new(self, None)
+    }
 
-        match self.try_for_each(|x| {
-            if let Some(val) = <usize as TryFromByAdd<
-                <<Self as Iterator>::Item as Deref>::Target,
-            >>::try_from_by_add(*x)
-            {
-                bitset.insert(val - min);
-                Some(())
-            } else {
-                None
-            }
-        }) {
-            Some(_) => UniqueSorted {
-                iter: bitset.into_ones(),
-                min,
-                _phantom: PhantomData,
-            },
-            _ => UniqueSorted::default(),
-        }
+    /// Return an iterator adapter that yields unique sorted integers using the given strategy.
+    ///
+    /// [`UniqueSortedStrategy::Bitset`] falls back to sorting when the range of the integers
+    /// spans more than 2<sup>32</sup> values, which would need a bit set larger than 512 MiB.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtd, UniqueSortedStrategy};
+    ///
+    /// let arr = [9u8, 5, 6, 4, 8, 8, 2, 4, 10, 2, 12];
+    /// let iter = arr.iter().unique_sorted_with(UniqueSortedStrategy::SortDedup);
+    /// assert_eq!(iter.strategy(), UniqueSortedStrategy::SortDedup);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 4, 5, 6, 8, 9, 10, 12]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn unique_sorted_with(self, strategy: UniqueSortedStrategy) -> UniqueSorted<Self>
+    where
+        Self: Iterator + Sized + Clone,
+        <Self as Iterator>::Item: Deref,
+        <<Self as Iterator>::Item as Deref>::Target: Copy + Ord,
+        u128: TryFromByAdd<<<Self as Iterator>::Item as Deref>::Target>,
+    {
+        UniqueSorted::new(self, Some(strategy))
     }
 }
 
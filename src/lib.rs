@@ -54,7 +54,21 @@
 //! let vec = iter.collect::<Vec<_>>();
 //! assert_eq!(vec, vec![[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5], [4, 5, 6], [5, 6, 7]]);
 //! ```
+//!
+//! ### Sliding array windows:
+//!
+//! ```
+//! use iterextd::IterExtd;
+//!
+//! let arr = [0, 1, 2, 3, 4, 5, 6, 7];
+//! let vec = arr.into_iter().array_windows::<3>().collect::<Vec<_>>();
+//! assert_eq!(vec, vec![[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5], [4, 5, 6], [5, 6, 7]]);
+//! ```
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+mod error;
 mod gen_iterator;
 mod integer_scaling_iterator;
 #[doc = include_str!("../README.md")]
@@ -63,9 +77,17 @@ mod slice_modify_iter;
 mod structs;
 mod iter_2d;
 
+pub use crate::error::IterExtdError;
 pub use crate::gen_iterator::CircleBresenhamSeq;
+pub use crate::integer_scaling_iterator::NonFinite;
+pub use crate::integer_scaling_iterator::ScaleMap;
 pub use crate::integer_scaling_iterator::Scaler;
 pub use crate::integer_scaling_iterator::Scaling;
+pub use crate::integer_scaling_iterator::ScalingAuto;
+pub use crate::integer_scaling_iterator::ScalingCurve;
+pub use crate::integer_scaling_iterator::ScalingFloat;
+pub use crate::integer_scaling_iterator::ScalingFrom;
+pub use crate::integer_scaling_iterator::ScalingFromChecked;
 pub use crate::iterator::IterExtd;
 pub use crate::iter_2d::Iter2D;
 pub use crate::iterator::SwapIter;
@@ -74,26 +96,50 @@ pub use crate::iterator::TupleIter;
 pub use crate::slice_modify_iter::GenRangeBounds;
 pub use crate::slice_modify_iter::SliceModify;
 pub use crate::slice_modify_iter::SliceModifyIter;
+pub use crate::iter_2d::Crop2D;
+pub use crate::iter_2d::Flip2D;
 pub use crate::iter_2d::Overlay2D;
+pub use crate::iter_2d::Overlay2DClipped;
+pub use crate::iter_2d::Overlay2DTransparent;
+pub use crate::iter_2d::Overlay2DWith;
+pub use crate::iter_2d::Rotation2D;
 pub use crate::structs::Adapter;
 pub use crate::structs::ArrChunks;
 pub use crate::structs::ArrayCloned;
 pub use crate::structs::ArrayCopied;
+pub use crate::structs::ArrayWindows;
 pub use crate::structs::CombineIters;
+pub use crate::structs::ConsecutiveRanges;
+pub use crate::structs::ExpandRanges;
+pub use crate::structs::ExpandRuns;
 pub use crate::structs::Extrapolate;
+pub use crate::structs::ExtrapolateN;
+pub use crate::structs::ExtrapolateWith;
+pub use crate::structs::Extrapolation;
 pub use crate::structs::GenCirclePoints;
 pub use crate::structs::InclusiveStepBy;
+pub use crate::structs::Interpolate;
 pub use crate::structs::LastTaken;
+pub use crate::structs::MapBy;
+pub use crate::structs::MapByPad;
+pub use crate::structs::MapByRest;
 pub use crate::structs::MapByThree;
 pub use crate::structs::MapByTwo;
 pub use crate::structs::MapIters;
 pub use crate::structs::MissingIntegers;
+pub use crate::structs::MissingRanges;
+pub use crate::structs::MissingRangesIn;
 pub use crate::structs::Offset;
+pub use crate::structs::PartialArray;
 pub use crate::structs::Previous;
 pub use crate::structs::RangeIcvToTup;
 pub use crate::structs::RangeToTup;
+pub use crate::structs::Runs;
+pub use crate::structs::Rounding;
+pub use crate::structs::SetOpSorted;
 pub use crate::structs::SkipStepBy;
 pub use crate::structs::SliceCopied;
+pub use crate::structs::Stats;
 pub use crate::structs::StepBoundary;
 pub use crate::structs::StepByFn;
 pub use crate::structs::TakeSkipCyclic;
@@ -101,7 +147,23 @@ pub use crate::structs::TupToRange;
 pub use crate::structs::TupToRangeIcv;
 pub use crate::structs::TupleImut;
 pub use crate::structs::TupleMut;
+pub use crate::structs::UnexpectedIntegersIn;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::iter_2d::Remap2D;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::structs::FreqOrder;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::structs::HistogramBin;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::structs::UniqueSorted;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::structs::UniqueSortedStrategy;
 #[cfg(feature = "itern")]
 #[cfg_attr(docsrs, doc(cfg(feature = "itern")))]
 pub use crate::iterator::trait_itern::TupleItern;
@@ -110,17 +172,28 @@ use core::hash::Hash;
 #[cfg(feature = "std")]
 use std::collections::HashMap;
 
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::vec::{self, Vec};
 use core::array::IntoIter;
 use core::fmt::{self, Debug};
-use core::iter::{Fuse, FusedIterator};
+use core::iter::{self, Fuse, FusedIterator, Peekable};
 use core::marker::PhantomData;
 use core::mem::{swap, MaybeUninit};
+#[cfg(feature = "alloc")]
+use core::ops::{Bound, RangeBounds};
 use core::ops::{Add, AddAssign, Deref, Div, Mul, Range, RangeInclusive, Sub};
 use core::ptr;
 use core::slice::SliceIndex;
+#[cfg(feature = "alloc")]
 use fixedbitset::{FixedBitSet, IntoOnes};
 use itertools::Itertools;
 use itertools::MinMaxResult::{MinMax, NoElements, OneElement};
-use num::{one, zero, Bounded, CheckedMul, CheckedSub, One, Zero};
-use num_convert::{ToZero, TryFromByAdd};
+#[cfg(feature = "alloc")]
+use num::traits::CheckedRem;
+#[cfg(feature = "alloc")]
+use num::Signed;
+use num::{zero, Bounded, CheckedMul, CheckedSub, Float, NumCast, One, PrimInt, Zero};
+use num_convert::TryFromByAdd;
 use num_integer::{gcd, Integer};
@@ -1,4 +1,4 @@
-use crate::{SliceIndex, IterExtd, StepBoundary};
+use crate::{SliceIndex, IterExtd, IterExtdError, StepBoundary};
 use crate::{Range, RangeInclusive};
 use crate::PhantomData;
 
@@ -60,6 +60,65 @@ where
         (0..self.as_ref().len()).step_boundary(size)
     }
 
+    /// Create an iterator that returns [`RangeInclusive`] structures with index bounds from the slice,
+    /// returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`gen_range_bounds`](SliceModifyIter::gen_range_bounds).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::ZeroSize`] if the step size is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, SliceModifyIter};
+    ///
+    /// let val = vec![0, 1, 2, 3, 4];
+    /// assert_eq!(val.try_gen_range_bounds(0).err(), Some(IterExtdError::ZeroSize));
+    ///
+    /// let vec = val.try_gen_range_bounds(2).unwrap().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![0..=1, 2..=3, 4..=4]);
+    /// ```
+    #[inline]
+    fn try_gen_range_bounds(&self, size: usize) -> Result<GenRangeBounds, IterExtdError> {
+        Ok(GenRangeBounds {
+            iter: (0..self.as_ref().len()).try_step_boundary(size)?,
+        })
+    }
+
+    /// Create an iterator that returns tuples with index bounds from the slice,
+    /// returning an error instead of panicking.
+    ///
+    /// This is the fallible counterpart of [`gen_tuple_bounds`](SliceModifyIter::gen_tuple_bounds).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IterExtdError::ZeroSize`] if the step size is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtdError, SliceModifyIter};
+    ///
+    /// let val = vec![0, 1, 2, 3, 4];
+    /// assert_eq!(val.try_gen_tuple_bounds(0).err(), Some(IterExtdError::ZeroSize));
+    ///
+    /// let vec = val.try_gen_tuple_bounds(2).unwrap().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 1), (2, 3), (4, 4)]);
+    /// ```
+    #[inline]
+    fn try_gen_tuple_bounds(
+        &self,
+        size: usize,
+    ) -> Result<StepBoundary<Range<usize>>, IterExtdError> {
+        (0..self.as_ref().len()).try_step_boundary(size)
+    }
+
     /// Modify a slice using an iterator with external slice indexing.
     ///
     /// # Examples
@@ -1,13 +1,28 @@
 use crate::fmt;
+use crate::iter;
 use crate::ptr;
+#[cfg(feature = "alloc")]
+use crate::Deref;
+use crate::IterExtdError;
 use crate::MaybeUninit;
+use crate::One;
 use crate::PhantomData;
+#[cfg(feature = "alloc")]
 use crate::TryFromByAdd;
-use crate::{Add, AddAssign, Deref, Sub};
+use crate::Zero;
+#[cfg(feature = "alloc")]
+use crate::{vec, Vec};
+use crate::{Add, AddAssign, Div, Mul, Sub};
+#[cfg(feature = "alloc")]
+use crate::{Bound, RangeBounds};
+#[cfg(feature = "alloc")]
 use crate::{FixedBitSet, IntoOnes};
-use crate::{Fuse, FusedIterator};
+use crate::{Fuse, FusedIterator, Peekable};
 use crate::{Range, RangeInclusive};
-use num::{traits::FloatConst, Float, NumCast};
+#[cfg(feature = "alloc")]
+use num::{traits::CheckedRem, CheckedMul};
+use num::{traits::FloatConst, CheckedSub, Float, NumCast, PrimInt};
+use num_integer::{gcd, Integer};
 
 /// Universal adapter for iterators.
 #[derive(Clone)]
@@ -147,10 +162,168 @@ where
 {
 }
 
-/// Returns an iterator of arrays with N elements.
+/// An iterator over overlapping windows of N elements.
 #[derive(Debug, Clone)]
-pub struct ArrChunks<I, const N: usize> {
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    iter: I,
+    window: Option<[I::Item; N]>,
+    head: usize,
+}
+
+impl<I: Iterator, const N: usize> ArrayWindows<I, N> {
+    #[inline]
+    pub(super) fn new(iter: I) -> ArrayWindows<I, N> {
+        ArrayWindows {
+            iter,
+            window: None,
+            head: 0,
+        }
+    }
+
+    #[inline]
+    fn windows_count(&self, len: usize) -> usize {
+        if self.window.is_some() {
+            len
+        } else {
+            len.saturating_sub(N - 1)
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.window {
+            Some(window) => {
+                window[self.head] = self.iter.next()?;
+                self.head = (self.head + 1) % N;
+                Some(core::array::from_fn(|idx| {
+                    window[(self.head + idx) % N].clone()
+                }))
+            }
+            None => {
+                let window = PartialArray::new().fill(&mut self.iter).ok()?;
+                self.window = Some(window.clone());
+                Some(window)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+
+        (
+            self.windows_count(lower),
+            upper.map(|n| self.windows_count(n)),
+        )
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for ArrayWindows<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I, const N: usize> FusedIterator for ArrayWindows<I, N>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
+
+/// Returns an iterator of arrays with N elements.
+///
+/// The trailing elements that do not fill a whole array are kept and can be taken
+/// with [`into_remainder`](ArrChunks::into_remainder).
+/// The `T` parameter is the element type and defaults to the item type of `I`.
+pub struct ArrChunks<I, const N: usize, T = <I as Iterator>::Item> {
     pub(crate) iter: I,
+    remainder: Option<PartialArray<T, N>>,
+}
+
+impl<I: Iterator, const N: usize> ArrChunks<I, N> {
+    #[inline]
+    pub(super) fn new(iter: I) -> ArrChunks<I, N> {
+        ArrChunks {
+            iter,
+            remainder: None,
+        }
+    }
+
+    /// Returns the elements that are not going to be returned as an array.
+    ///
+    /// The remainder is known once the base iterator has run out, or after the first call to
+    /// [`next_back`](DoubleEndedIterator::next_back). Until then `None` is returned.
+    /// The remainder holds at most N - 1 elements.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let mut iter = "chunks".chars().arr_chunks::<4>();
+    /// assert_eq!(iter.next(), Some(['c', 'h', 'u', 'n']));
+    /// assert_eq!(iter.next(), None);
+    /// let rem = iter.into_remainder().unwrap();
+    /// assert_eq!(rem.as_slice(), &['k', 's']);
+    /// ```
+    #[inline]
+    pub fn into_remainder(self) -> Option<PartialArray<I::Item, N>> {
+        self.remainder
+    }
+
+    #[inline]
+    pub(crate) fn take_remainder(&mut self) -> Option<PartialArray<I::Item, N>> {
+        self.remainder.take().filter(|rem| !rem.is_empty())
+    }
+
+    #[inline]
+    fn ceil_size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let rem = self.remainder.as_ref().map_or(0, |rem| rem.len().min(1));
+
+        (
+            lower.div_ceil(N) + rem,
+            upper.and_then(|n| n.div_ceil(N).checked_add(rem)),
+        )
+    }
+}
+
+impl<I, const N: usize, T> fmt::Debug for ArrChunks<I, N, T>
+where
+    I: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ArrChunks")
+            .field("iter", &self.iter)
+            .field("remainder", &self.remainder)
+            .finish()
+    }
+}
+
+impl<I, const N: usize, T> Clone for ArrChunks<I, N, T>
+where
+    I: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        ArrChunks {
+            iter: self.iter.clone(),
+            remainder: self.remainder.clone(),
+        }
+    }
 }
 
 impl<I, const N: usize> Iterator for ArrChunks<I, N>
@@ -161,21 +334,13 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let mut arr = unsafe { MaybeUninit::<[MaybeUninit<I::Item>; N]>::uninit().assume_init() };
-        let mut idx = 0;
-
-        loop {
-            if let Some(elem) = self.iter.next() {
-                if idx == N - 1 {
-                    arr[idx] = MaybeUninit::new(elem);
-                    break Some(unsafe { arr.map(|e| e.assume_init()) });
-                } else {
-                    arr[idx] = MaybeUninit::new(elem);
-                    idx += 1;
-                    continue;
+        match PartialArray::new().fill(&mut self.iter) {
+            Ok(arr) => Some(arr),
+            Err(partial) => {
+                if self.remainder.is_none() {
+                    self.remainder = Some(partial);
                 }
-            } else {
-                return None;
+                None
             }
         }
     }
@@ -193,6 +358,24 @@ where
     }
 }
 
+impl<I, const N: usize> DoubleEndedIterator for ArrChunks<I, N>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remainder.is_none() {
+            let count = self.iter.len() % N;
+            self.remainder = Some(PartialArray::new().fill_back(&mut self.iter, count));
+        }
+
+        PartialArray::new()
+            .fill_back(&mut self.iter, N)
+            .into_array()
+            .ok()
+    }
+}
+
 /// Combine two iterators in pieces, specifying the length of each separately.
 #[derive(Debug, Clone)]
 pub struct CombineIters<I, J> {
@@ -259,383 +442,1985 @@ where
     }
 }
 
-/// Store data for generating circle points.
+/// Extrapolation modes for the [`extrapolate_with`] and [`extrapolate_n`] adapters.
+///
+/// [`extrapolate_with`]: crate::IterExtd::extrapolate_with
+/// [`extrapolate_n`]: crate::IterExtd::extrapolate_n
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Extrapolation {
+    /// Holds the last element.
+    Constant,
+    /// Continues with the difference of the last two elements.
+    Linear,
+    /// Continues the polynomial of degree `N - 1` passing through the last `N` elements.
+    Polynomial,
+    /// Continues with the ratio of the last two elements.
+    Geometric,
+}
+
+impl Extrapolation {
+    #[inline]
+    fn min_points(self) -> usize {
+        match self {
+            Extrapolation::Constant | Extrapolation::Polynomial => 1,
+            Extrapolation::Linear | Extrapolation::Geometric => 2,
+        }
+    }
+}
+
+/// A struct for the extrapolate with mode iterator adapter.
 #[derive(Debug, Clone)]
-pub struct GenCirclePoints<T> {
-    radius: T,
-    num_points: usize,
-    base_cos: T,
-    base_sin: T,
-    incr_cos: T,
-    incr_sin: T,
+pub struct ExtrapolateWith<I: Iterator, const N: usize> {
+    iter: Fuse<I>,
+    mode: Extrapolation,
+    history: [I::Item; N],
+    len: usize,
 }
 
-impl<T> GenCirclePoints<T>
+impl<I, const N: usize> ExtrapolateWith<I, N>
 where
-    T: Add<T> + Float + NumCast + FloatConst,
+    I: Iterator,
+    I::Item: Zero,
 {
-    /// Create a new instance of `GenCirclePoints`.
-    ///
-    /// # Arguments
-    ///
-    /// * `radius` - radius of the generated circle points.
-    /// * `num_points` - sets the number of points.
-    ///
-    /// # Examples
-    ///
-    /// Basic usage:
-    ///
-    /// ```
-    /// use iterextd::GenCirclePoints;
-    ///
-    /// let mut circle_points = GenCirclePoints::new(5.0_f64, 3);
-    /// let points = circle_points.collect::<Vec<_>>();
-    /// let formatted_points = format!("{:.3?}", points);
-    /// assert_eq!(formatted_points, "[(5.000, 0.000), (-2.500, 4.330), (-2.500, -4.330)]");
-    /// ```
     #[inline]
-    pub fn new(radius: T, num_points: usize) -> Self {
-        let incr = T::TAU() / T::from(num_points).unwrap();
-        Self {
-            radius,
-            num_points,
-            base_cos: T::from(1.0).unwrap(),
-            base_sin: T::from(0.0).unwrap(),
-            incr_cos: T::from(2.0).unwrap() * ((incr * T::from(0.5).unwrap()).sin()).powi(2),
-            incr_sin: incr.sin(),
+    pub(super) fn new(iter: I, mode: Extrapolation) -> ExtrapolateWith<I, N> {
+        assert!(
+            N >= mode.min_points(),
+            "the number of points is too small for the extrapolation mode"
+        );
+
+        ExtrapolateWith {
+            iter: iter.fuse(),
+            mode,
+            history: core::array::from_fn(|_| I::Item::zero()),
+            len: 0,
         }
     }
 }
 
-impl<T> Iterator for GenCirclePoints<T>
+impl<I, const N: usize> ExtrapolateWith<I, N>
 where
-    T: Float,
+    I: Iterator,
+    I::Item: Copy
+        + PartialEq
+        + Zero
+        + Add<Output = I::Item>
+        + Sub<Output = I::Item>
+        + Mul<Output = I::Item>
+        + Div<Output = I::Item>,
 {
-    type Item = (T, T);
+    #[inline]
+    fn push(&mut self, elem: I::Item) {
+        if self.len < N {
+            self.history[self.len] = elem;
+            self.len += 1;
+        } else {
+            self.history.rotate_left(1);
+            self.history[N - 1] = elem;
+        }
+    }
+
+    fn synthesize(&mut self) -> Option<I::Item> {
+        let last = *self.history[..self.len].last()?;
+        let res = match self.mode {
+            Extrapolation::Constant => last,
+            Extrapolation::Geometric => {
+                let prev = self.history[self.len.saturating_sub(2)];
+                if self.len < 2 || prev == I::Item::zero() {
+                    last
+                } else {
+                    last * last / prev
+                }
+            }
+            Extrapolation::Linear | Extrapolation::Polynomial => {
+                let order = match self.mode {
+                    Extrapolation::Linear => 2,
+                    _ => N,
+                };
+                let order = order.min(self.len);
+                let mut diffs = self.history;
+                let diffs = &mut diffs[self.len - order..self.len];
+
+                // After each level the last element of the row holds the backward
+                // difference of that order at the last point.
+                for level in 1..order {
+                    for idx in 0..order - level {
+                        diffs[idx] = diffs[idx + 1] - diffs[idx];
+                    }
+                }
+
+                diffs.iter().fold(I::Item::zero(), |acc, &diff| acc + diff)
+            }
+        };
+        self.push(res);
+        Some(res)
+    }
+}
+
+impl<I, const N: usize> Iterator for ExtrapolateWith<I, N>
+where
+    I: Iterator,
+    I::Item: Copy
+        + PartialEq
+        + Zero
+        + Add<Output = I::Item>
+        + Sub<Output = I::Item>
+        + Mul<Output = I::Item>
+        + Div<Output = I::Item>,
+{
+    type Item = I::Item;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.num_points == 0 {
-            return None;
+        if let Some(elem) = self.iter.next() {
+            self.push(elem);
+            Some(elem)
+        } else {
+            self.synthesize()
         }
-        self.num_points -= 1;
-
-        let cos_sin = (self.base_cos * self.radius, self.base_sin * self.radius);
-        let tmp_sin = self.base_sin;
-        self.base_sin = (self.incr_sin * self.base_cos - self.incr_cos * tmp_sin) + tmp_sin;
-        self.base_cos = (-self.incr_sin * tmp_sin - self.incr_cos * self.base_cos) + self.base_cos;
-        Some(cos_sin)
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.num_points, Some(self.num_points))
+        let (lower, upper) = self.iter.size_hint();
+        if self.len == 0 && upper == Some(0) {
+            (0, Some(0))
+        } else if self.len == 0 && lower == 0 {
+            (0, None)
+        } else {
+            (usize::MAX, None)
+        }
     }
 }
 
-impl<T: Float> ExactSizeIterator for GenCirclePoints<T> {}
-impl<T: Float> FusedIterator for GenCirclePoints<T> {}
+impl<I, const N: usize> FusedIterator for ExtrapolateWith<I, N>
+where
+    I: Iterator,
+    I::Item: Copy
+        + PartialEq
+        + Zero
+        + Add<Output = I::Item>
+        + Sub<Output = I::Item>
+        + Mul<Output = I::Item>
+        + Div<Output = I::Item>,
+{
+}
 
-/// An iterator to determine the boundaries of each step in a sequence.
-#[derive(Debug, Clone)]
-pub struct StepBoundary<I> {
-    iter: I,
-    size: usize,
+/// A struct for the bounded extrapolate iterator adapter.
+pub struct ExtrapolateN<I: Iterator, const N: usize> {
+    iter: ExtrapolateWith<I, N>,
+    count: usize,
 }
 
-impl<I: Iterator> StepBoundary<I> {
+impl<I, const N: usize> Clone for ExtrapolateN<I, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        ExtrapolateN {
+            iter: self.iter.clone(),
+            count: self.count,
+        }
+    }
+}
+
+impl<I, const N: usize> fmt::Debug for ExtrapolateN<I, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ExtrapolateN")
+            .field("iter", &self.iter)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<I: Iterator, const N: usize> ExtrapolateN<I, N> {
     #[inline]
-    pub(super) fn new(iter: I, size: usize) -> StepBoundary<I> {
-        StepBoundary { iter, size }
+    pub(super) fn new(iter: ExtrapolateWith<I, N>, count: usize) -> ExtrapolateN<I, N> {
+        ExtrapolateN { iter, count }
     }
 }
 
-impl<I> Iterator for StepBoundary<I>
+impl<I, const N: usize> Iterator for ExtrapolateN<I, N>
 where
     I: Iterator,
-    <I as Iterator>::Item: Copy,
+    I::Item: Copy
+        + PartialEq
+        + Zero
+        + Add<Output = I::Item>
+        + Sub<Output = I::Item>
+        + Mul<Output = I::Item>
+        + Div<Output = I::Item>,
 {
-    type Item = (I::Item, I::Item);
+    type Item = I::Item;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let start = self.iter.next()?;
-        let mut start_end = (start, start);
-        for _ in 1..self.size {
-            if let Some(e) = self.iter.next() {
-                start_end.1 = e;
-            } else {
-                return Some(start_end);
+        if let Some(elem) = self.iter.iter.next() {
+            self.iter.push(elem);
+            return Some(elem);
+        }
+
+        if self.count == 0 {
+            return None;
+        }
+        match self.iter.synthesize() {
+            Some(elem) => {
+                self.count -= 1;
+                Some(elem)
+            }
+            None => {
+                self.count = 0;
+                None
             }
         }
-        Some(start_end)
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (lower, upper) = self.iter.size_hint();
-        let div = |x: usize| {
-            if x == 0 {
-                0
-            } else {
-                1 + (x - 1) / self.size
-            }
+        let (lower, upper) = self.iter.iter.size_hint();
+        if self.iter.len == 0 && upper == Some(0) {
+            return (0, Some(0));
+        }
+        let lower = if self.iter.len == 0 && lower == 0 {
+            0
+        } else {
+            lower.saturating_add(self.count)
         };
 
-        (div(lower), upper.map(div))
+        (lower, upper.and_then(|n| n.checked_add(self.count)))
     }
 }
 
-/// Iterator to step iterators by a given amount including the first and last element.
-#[derive(Debug, Clone)]
-pub struct InclusiveStepBy<I> {
-    iter: I,
-    firs_step: bool,
-    step: usize,
-}
-
-impl<I> ExactSizeIterator for StepBoundary<I>
+impl<I, const N: usize> ExactSizeIterator for ExtrapolateN<I, N>
 where
     I: ExactSizeIterator,
-    <I as Iterator>::Item: Copy,
+    I::Item: Copy
+        + PartialEq
+        + Zero
+        + Add<Output = I::Item>
+        + Sub<Output = I::Item>
+        + Mul<Output = I::Item>
+        + Div<Output = I::Item>,
 {
 }
 
-impl<I> Iterator for InclusiveStepBy<I>
+impl<I, const N: usize> FusedIterator for ExtrapolateN<I, N>
 where
     I: Iterator,
+    I::Item: Copy
+        + PartialEq
+        + Zero
+        + Add<Output = I::Item>
+        + Sub<Output = I::Item>
+        + Mul<Output = I::Item>
+        + Div<Output = I::Item>,
+{
+}
+
+/// Orders of the frequencies counted by [`count_freq_sorted`].
+///
+/// [`count_freq_sorted`]: crate::IterExtd::count_freq_sorted
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FreqOrder {
+    /// Ascending order of the elements.
+    Key,
+    /// Descending order of the counts.
+    Count,
+}
+
+/// The lower and upper bound of a bin yielded by [`histogram`].
+///
+/// [`histogram`]: crate::IterExtd::histogram
+#[cfg(feature = "alloc")]
+pub type HistogramBin<T> = (Bound<T>, Bound<T>);
+
+/// One-pass descriptive statistics of numeric elements, created by [`stats`].
+///
+/// Signed integers are summed exactly in `i128`, unsigned integers in `u128` and fractional
+/// values in `f64`, so the sum does not overflow the element type. The mean and the variances
+/// are computed in `f64` with Welford's algorithm. NaN values propagate to the sum, mean and
+/// variances but are skipped by the minimum and maximum.
+///
+/// [`stats`]: crate::IterExtd::stats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats<T> {
+    count: usize,
+    int_sum: Option<i128>,
+    uint_sum: Option<u128>,
+    float_sum: f64,
+    min: Option<T>,
+    max: Option<T>,
+    mean: f64,
+    m2: f64,
+}
+
+impl<T> Stats<T>
+where
+    T: Copy + PartialOrd + NumCast,
+{
+    /// Creates empty statistics.
+    #[inline]
+    pub fn new() -> Self {
+        Stats {
+            count: 0,
+            int_sum: Some(0),
+            uint_sum: Some(0),
+            float_sum: 0.0,
+            min: None,
+            max: None,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Adds a value to the statistics.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.count += 1;
+        if is_fractional::<T>() {
+            self.float_sum += <f64 as NumCast>::from(value).unwrap_or(f64::NAN);
+        } else if is_unsigned::<T>() {
+            self.uint_sum = self
+                .uint_sum
+                .and_then(|sum| sum.checked_add(value.to_u128()?));
+        } else {
+            self.int_sum = self
+                .int_sum
+                .and_then(|sum| sum.checked_add(value.to_i128()?));
+        }
+        if value.partial_cmp(&value).is_some() {
+            match self.min {
+                Some(min) if min <= value => {}
+                _ => self.min = Some(value),
+            }
+            match self.max {
+                Some(max) if max >= value => {}
+                _ => self.max = Some(value),
+            }
+        }
+
+        let value = <f64 as NumCast>::from(value).unwrap_or(f64::NAN);
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Returns the number of values.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the sum of the values, zero if there are none.
+    ///
+    /// Returns `None` if the sum is not representable in `T`.
+    #[inline]
+    pub fn sum(&self) -> Option<T> {
+        if is_fractional::<T>() {
+            T::from(self.float_sum)
+        } else if is_unsigned::<T>() {
+            T::from(self.uint_sum?)
+        } else {
+            T::from(self.int_sum?)
+        }
+    }
+
+    /// Returns the minimum value, `None` if there are none.
+    #[inline]
+    pub fn min(&self) -> Option<T> {
+        self.min
+    }
+
+    /// Returns the maximum value, `None` if there are none.
+    #[inline]
+    pub fn max(&self) -> Option<T> {
+        self.max
+    }
+
+    /// Returns the arithmetic mean, `None` if there are no values.
+    #[inline]
+    pub fn mean(&self) -> Option<f64> {
+        (self.count != 0).then_some(self.mean)
+    }
+
+    /// Returns the population variance, `None` if there are no values.
+    #[inline]
+    pub fn variance(&self) -> Option<f64> {
+        (self.count != 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Returns the sample variance, `None` if there are fewer than two values.
+    #[inline]
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+}
+
+impl<T> Default for Stats<T>
+where
+    T: Copy + PartialOrd + NumCast,
+{
+    #[inline]
+    fn default() -> Self {
+        Stats::new()
+    }
+}
+
+impl<T> Extend<T> for Stats<T>
+where
+    T: Copy + PartialOrd + NumCast,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+/// Rounding modes for integer results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds to the nearest value, ties towards positive infinity.
+    Nearest,
+    /// Rounds towards positive infinity.
+    Ceil,
+}
+
+/// A struct for the interpolate iterator adapter.
+#[derive(Debug, Clone)]
+pub struct Interpolate<I: Iterator> {
+    iter: I,
+    prev: Option<I::Item>,
+    target: Option<I::Item>,
+    count: usize,
+    step: usize,
+    rounding: Option<Rounding>,
+}
+
+impl<I> Interpolate<I>
+where
+    I: Iterator,
+    I::Item: NumCast,
+{
+    #[inline]
+    pub(super) fn new(iter: I, count: usize, rounding: Option<Rounding>) -> Interpolate<I> {
+        // Integers are interpolated in `u128`, only fractional types need the number of parts.
+        let parts = count.checked_add(1).filter(|&parts| {
+            !is_fractional::<I::Item>() || <I::Item as NumCast>::from(parts).is_some()
+        });
+        assert!(
+            parts.is_some(),
+            "the number of inserted values must be less than usize::MAX"
+        );
+
+        Interpolate {
+            iter,
+            prev: None,
+            target: None,
+            count,
+            step: 0,
+            rounding,
+        }
+    }
+}
+
+#[inline]
+fn cast<T: NumCast>(value: usize) -> T {
+    T::from(value).expect("the value is checked at construction")
+}
+
+/// Returns `true` if `T` represents fractional values, such as the floating point types.
+#[inline]
+pub(crate) fn is_fractional<T: NumCast>() -> bool {
+    T::from(0.5).and_then(|half| half.to_f64()) == Some(0.5)
+}
+
+/// Returns `true` if `T` cannot represent negative values, such as the unsigned integer types.
+#[inline]
+fn is_unsigned<T: NumCast>() -> bool {
+    T::from(-1).is_none()
+}
+
+/// Returns the value `step / parts` of the way from `start` to `end`.
+///
+/// Integers are computed without overflow and rounded with `rounding`,
+/// `None` truncates the offset towards `start`. Fractional types ignore `rounding`.
+#[inline]
+pub(crate) fn interpolate_value<T>(
+    start: T,
+    end: T,
+    step: usize,
+    parts: usize,
+    rounding: Option<Rounding>,
+) -> T
+where
+    T: Copy
+        + PartialOrd
+        + NumCast
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    if !is_fractional::<T>() {
+        return interpolate_int(start, end, step, parts, rounding);
+    }
+
+    if start <= end {
+        start + (end - start) * cast(step) / cast(parts)
+    } else {
+        start - (start - end) * cast(step) / cast(parts)
+    }
+}
+
+fn interpolate_int<T>(start: T, end: T, step: usize, parts: usize, rounding: Option<Rounding>) -> T
+where
+    T: Copy + PartialOrd + NumCast,
+{
+    let ascending = start <= end;
+    let diff = if ascending {
+        int_offset(start, end)
+    } else {
+        int_offset(end, start)
+    };
+    let (step, parts) = (step as u128, parts as u128);
+    // The remainder is less than `parts`, so its product with `step` always fits in `u128`.
+    let (quot, rem) = (diff / parts, diff % parts);
+    let (rem_quot, rem) = ((rem * step) / parts, (rem * step) % parts);
+    // The offset is rounded away from the start when that moves the value in the rounding direction.
+    let round_away = match (rounding, ascending) {
+        _ if rem == 0 => false,
+        (None, _) => false,
+        (Some(Rounding::Floor), ascending) => !ascending,
+        (Some(Rounding::Ceil), ascending) => ascending,
+        (Some(Rounding::Nearest), true) => rem >= parts - rem,
+        (Some(Rounding::Nearest), false) => rem > parts - rem,
+    };
+    let offset = quot * step + rem_quot + round_away as u128;
+
+    int_towards(start, end, offset)
+}
+
+/// Moves `start` by `offset` towards `end`, the result must not pass `end`.
+#[inline]
+fn int_towards<T: NumCast>(start: T, end: T, offset: u128) -> T {
+    let value = match (start.to_u128(), end.to_u128()) {
+        (Some(start), Some(end)) if start <= end => T::from(start + offset),
+        (Some(start), Some(_)) => T::from(start - offset),
+        // A negative bound, so both integers are of a signed type that fits in `i128`.
+        _ => {
+            let start = start.to_i128().expect("signed integers fit in i128");
+            let end = end.to_i128().expect("signed integers fit in i128");
+            let value = if start <= end {
+                (start as u128).wrapping_add(offset)
+            } else {
+                (start as u128).wrapping_sub(offset)
+            };
+            T::from(value as i128)
+        }
+    };
+
+    value.expect("the value lies between start and end")
+}
+
+impl<I> Iterator for Interpolate<I>
+where
+    I: Iterator,
+    I::Item: Copy
+        + PartialOrd
+        + NumCast
+        + Add<Output = I::Item>
+        + Sub<Output = I::Item>
+        + Mul<Output = I::Item>
+        + Div<Output = I::Item>,
 {
     type Item = I::Item;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.firs_step {
-            self.firs_step = false;
-            return self.iter.next();
+        let Some(prev) = self.prev else {
+            self.prev = self.iter.next();
+            return self.prev;
+        };
+        let target = match self.target {
+            Some(target) => target,
+            None => {
+                let target = self.iter.next()?;
+                self.target = Some(target);
+                self.step = 0;
+                target
+            }
+        };
+
+        if self.step < self.count {
+            self.step += 1;
+            Some(interpolate_value(
+                prev,
+                target,
+                self.step,
+                self.count + 1,
+                self.rounding,
+            ))
+        } else {
+            self.prev = self.target.take();
+            self.prev
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let per_elem = self.count + 1;
+        let remaining = |len: usize| -> Option<usize> {
+            match (self.prev, self.target) {
+                (None, _) => match len {
+                    0 => Some(0),
+                    len => (len - 1).checked_mul(per_elem)?.checked_add(1),
+                },
+                (Some(_), None) => len.checked_mul(per_elem),
+                (Some(_), Some(_)) => len
+                    .checked_mul(per_elem)?
+                    .checked_add(self.count - self.step + 1),
+            }
+        };
+
+        (
+            remaining(lower).unwrap_or(usize::MAX),
+            upper.and_then(remaining),
+        )
+    }
+}
+
+impl<I> ExactSizeIterator for Interpolate<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Copy
+        + PartialOrd
+        + NumCast
+        + Add<Output = I::Item>
+        + Sub<Output = I::Item>
+        + Mul<Output = I::Item>
+        + Div<Output = I::Item>,
+{
+}
+
+impl<I> FusedIterator for Interpolate<I>
+where
+    I: FusedIterator,
+    I::Item: Copy
+        + PartialOrd
+        + NumCast
+        + Add<Output = I::Item>
+        + Sub<Output = I::Item>
+        + Mul<Output = I::Item>
+        + Div<Output = I::Item>,
+{
+}
+
+/// Store data for generating circle points.
+#[derive(Debug, Clone)]
+pub struct GenCirclePoints<T> {
+    radius: T,
+    num_points: usize,
+    base_cos: T,
+    base_sin: T,
+    incr_cos: T,
+    incr_sin: T,
+}
+
+impl<T> GenCirclePoints<T>
+where
+    T: Add<T> + Float + NumCast + FloatConst,
+{
+    /// Create a new instance of `GenCirclePoints`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - radius of the generated circle points.
+    /// * `num_points` - sets the number of points.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::GenCirclePoints;
+    ///
+    /// let mut circle_points = GenCirclePoints::new(5.0_f64, 3);
+    /// let points = circle_points.collect::<Vec<_>>();
+    /// let formatted_points = format!("{:.3?}", points);
+    /// assert_eq!(formatted_points, "[(5.000, 0.000), (-2.500, 4.330), (-2.500, -4.330)]");
+    /// ```
+    #[inline]
+    pub fn new(radius: T, num_points: usize) -> Self {
+        let incr = T::TAU() / T::from(num_points).unwrap();
+        Self {
+            radius,
+            num_points,
+            base_cos: T::from(1.0).unwrap(),
+            base_sin: T::from(0.0).unwrap(),
+            incr_cos: T::from(2.0).unwrap() * ((incr * T::from(0.5).unwrap()).sin()).powi(2),
+            incr_sin: incr.sin(),
+        }
+    }
+}
+
+impl<T> Iterator for GenCirclePoints<T>
+where
+    T: Float,
+{
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num_points == 0 {
+            return None;
+        }
+        self.num_points -= 1;
+
+        let cos_sin = (self.base_cos * self.radius, self.base_sin * self.radius);
+        let tmp_sin = self.base_sin;
+        self.base_sin = (self.incr_sin * self.base_cos - self.incr_cos * tmp_sin) + tmp_sin;
+        self.base_cos = (-self.incr_sin * tmp_sin - self.incr_cos * self.base_cos) + self.base_cos;
+        Some(cos_sin)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.num_points, Some(self.num_points))
+    }
+}
+
+impl<T: Float> ExactSizeIterator for GenCirclePoints<T> {}
+impl<T: Float> FusedIterator for GenCirclePoints<T> {}
+
+/// An iterator to determine the boundaries of each step in a sequence.
+#[derive(Debug, Clone)]
+pub struct StepBoundary<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> StepBoundary<I> {
+    #[inline]
+    pub(super) fn new(iter: I, size: usize) -> StepBoundary<I> {
+        StepBoundary { iter, size }
+    }
+}
+
+impl<I> Iterator for StepBoundary<I>
+where
+    I: Iterator,
+    <I as Iterator>::Item: Copy,
+{
+    type Item = (I::Item, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.iter.next()?;
+        let mut start_end = (start, start);
+        for _ in 1..self.size {
+            if let Some(e) = self.iter.next() {
+                start_end.1 = e;
+            } else {
+                return Some(start_end);
+            }
+        }
+        Some(start_end)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let div = |x: usize| {
+            if x == 0 {
+                0
+            } else {
+                1 + (x - 1) / self.size
+            }
+        };
+
+        (div(lower), upper.map(div))
+    }
+}
+
+/// Iterator to step iterators by a given amount including the first and last element.
+#[derive(Debug, Clone)]
+pub struct InclusiveStepBy<I> {
+    iter: I,
+    firs_step: bool,
+    step: usize,
+}
+
+impl<I> ExactSizeIterator for StepBoundary<I>
+where
+    I: ExactSizeIterator,
+    <I as Iterator>::Item: Copy,
+{
+}
+
+impl<I> Iterator for InclusiveStepBy<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.firs_step {
+            self.firs_step = false;
+            return self.iter.next();
+        }
+        let mut elem = self.iter.next()?;
+        for _ in 1..self.step {
+            if let Some(e) = self.iter.next() {
+                elem = e;
+            } else {
+                return Some(elem);
+            }
+        }
+        Some(elem)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let div = |x: usize| {
+            if x == 0 {
+                0
+            } else {
+                (if (x - 1).wrapping_rem(self.step) == 0 {
+                    1
+                } else {
+                    2
+                }) + (x - 1) / self.step
+            }
+        };
+
+        (div(lower), upper.map(div))
+    }
+}
+
+impl<I: Iterator> InclusiveStepBy<I> {
+    #[inline]
+    pub(super) fn new(iter: I, step: usize) -> InclusiveStepBy<I> {
+        InclusiveStepBy {
+            iter,
+            firs_step: true,
+            step,
+        }
+    }
+}
+
+impl<I> ExactSizeIterator for InclusiveStepBy<I> where I: ExactSizeIterator {}
+
+/// An iterator that yields N elements each iteration as an array.
+pub struct MapBy<I: Iterator, F, const N: usize> {
+    iter: ArrChunks<I, N>,
+    f: F,
+}
+
+impl<I, F, const N: usize> Clone for MapBy<I, F, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        MapBy {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F, const N: usize> fmt::Debug for MapBy<I, F, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("MapBy")
+            .field("iter", &self.iter)
+            .field("f", &format_args!("{:p}", &self.f))
+            .finish()
+    }
+}
+
+impl<I: Iterator, F, const N: usize> MapBy<I, F, N> {
+    #[inline]
+    pub(super) fn new(iter: ArrChunks<I, N>, f: F) -> MapBy<I, F, N> {
+        MapBy { iter, f }
+    }
+}
+
+impl<B, F, I, const N: usize> Iterator for MapBy<I, F, N>
+where
+    I: Iterator,
+    F: FnMut([I::Item; N]) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(&mut self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<B, F, I, const N: usize> ExactSizeIterator for MapBy<I, F, N>
+where
+    I: ExactSizeIterator,
+    F: FnMut([I::Item; N]) -> B,
+{
+}
+
+/// An iterator that yields N elements each iteration as an array, padding the last partial array.
+#[derive(Clone)]
+pub struct MapByPad<I: Iterator, F, const N: usize> {
+    iter: ArrChunks<I, N>,
+    pad: I::Item,
+    f: F,
+}
+
+impl<I, F, const N: usize> fmt::Debug for MapByPad<I, F, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("MapByPad")
+            .field("iter", &self.iter)
+            .field("pad", &self.pad)
+            .field("f", &format_args!("{:p}", &self.f))
+            .finish()
+    }
+}
+
+impl<I: Iterator, F, const N: usize> MapByPad<I, F, N> {
+    #[inline]
+    pub(super) fn new(iter: ArrChunks<I, N>, pad: I::Item, f: F) -> MapByPad<I, F, N> {
+        MapByPad { iter, pad, f }
+    }
+}
+
+impl<B, F, I, const N: usize> Iterator for MapByPad<I, F, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut([I::Item; N]) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(arr) = self.iter.next() {
+            return Some((self.f)(arr));
+        }
+
+        let arr = self
+            .iter
+            .take_remainder()?
+            .fill(&mut iter::repeat(self.pad.clone()))
+            .ok()?;
+        Some((self.f)(arr))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.ceil_size_hint()
+    }
+}
+
+impl<B, F, I, const N: usize> ExactSizeIterator for MapByPad<I, F, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    F: FnMut([I::Item; N]) -> B,
+{
+}
+
+/// An iterator that yields N elements each iteration as an array,
+/// passing the last partial array to a second closure.
+pub struct MapByRest<I: Iterator, F, G, const N: usize> {
+    iter: ArrChunks<I, N>,
+    f: F,
+    g: G,
+}
+
+impl<I, F, G, const N: usize> Clone for MapByRest<I, F, G, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+    G: Clone,
+{
+    fn clone(&self) -> Self {
+        MapByRest {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+            g: self.g.clone(),
+        }
+    }
+}
+
+impl<I, F, G, const N: usize> fmt::Debug for MapByRest<I, F, G, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("MapByRest")
+            .field("iter", &self.iter)
+            .field("f", &format_args!("{:p}", &self.f))
+            .field("g", &format_args!("{:p}", &self.g))
+            .finish()
+    }
+}
+
+impl<I: Iterator, F, G, const N: usize> MapByRest<I, F, G, N> {
+    #[inline]
+    pub(super) fn new(iter: ArrChunks<I, N>, f: F, g: G) -> MapByRest<I, F, G, N> {
+        MapByRest { iter, f, g }
+    }
+}
+
+impl<B, F, G, I, const N: usize> Iterator for MapByRest<I, F, G, N>
+where
+    I: Iterator,
+    F: FnMut([I::Item; N]) -> B,
+    G: FnMut(&[I::Item]) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(arr) = self.iter.next() {
+            return Some((self.f)(arr));
+        }
+
+        let rest = self.iter.take_remainder()?;
+        Some((self.g)(rest.as_slice()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.ceil_size_hint()
+    }
+}
+
+impl<B, F, G, I, const N: usize> ExactSizeIterator for MapByRest<I, F, G, N>
+where
+    I: ExactSizeIterator,
+    F: FnMut([I::Item; N]) -> B,
+    G: FnMut(&[I::Item]) -> B,
+{
+}
+
+/// An iterator that yields three elements each iteration.
+#[derive(Clone)]
+pub struct MapByThree<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for MapByThree<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("MapByThree")
+            .field("iter", &self.iter)
+            .field("f", &format_args!("{:p}", &self.f))
+            .finish()
+    }
+}
+
+impl<I, F> MapByThree<I, F> {
+    #[inline]
+    pub(super) fn new(iter: I, f: F) -> MapByThree<I, F> {
+        MapByThree { iter, f }
+    }
+}
+
+impl<B, F, I> Iterator for MapByThree<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item, I::Item, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            None => None,
+            Some(a) => match self.iter.next() {
+                None => None,
+                Some(b) => match self.iter.next() {
+                    None => None,
+                    Some(c) => Some((self.f)(a, b, c)),
+                },
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+
+        (lower / 3, upper.map(|n| n / 3))
+    }
+}
+
+impl<B, F, I> ExactSizeIterator for MapByThree<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(I::Item, I::Item, I::Item) -> B,
+{
+}
+
+/// An iterator that yields two elements each iteration.
+#[derive(Clone)]
+pub struct MapByTwo<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for MapByTwo<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("MapByTwo")
+            .field("iter", &self.iter)
+            .field("f", &format_args!("{:p}", &self.f))
+            .finish()
+    }
+}
+
+impl<I, F> MapByTwo<I, F> {
+    #[inline]
+    pub(super) fn new(iter: I, f: F) -> MapByTwo<I, F> {
+        MapByTwo { iter, f }
+    }
+}
+
+impl<B, F, I> Iterator for MapByTwo<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<B> {
+        match self.iter.next() {
+            None => None,
+            Some(x) => match self.iter.next() {
+                None => None,
+                Some(y) => Some((self.f)(x, y)),
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+
+        (lower / 2, upper.map(|n| n / 2))
+    }
+}
+
+impl<B, F, I> ExactSizeIterator for MapByTwo<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(I::Item, I::Item) -> B,
+{
+}
+
+/// Iterator adapter which provides two iterators for its closure at each iteration.
+#[derive(Clone)]
+pub struct MapIters<I, K, F> {
+    iter_self: I,
+    iter_other: K,
+    f: F,
+}
+
+impl<I, K, F> fmt::Debug for MapIters<I, K, F>
+where
+    I: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("MapIters")
+            .field("iter_self", &self.iter_self)
+            .field("iter_other", &self.iter_other)
+            .field("f", &format_args!("{:p}", &self.f))
+            .finish()
+    }
+}
+
+impl<I, K, F, B> MapIters<I, K, F>
+where
+    I: Iterator,
+    K: Iterator,
+    F: FnMut(&mut I, &mut K) -> Option<B>,
+{
+    #[inline]
+    pub(super) fn new(iter_self: I, iter_other: K, f: F) -> MapIters<I, K, F> {
+        MapIters {
+            iter_self,
+            iter_other,
+            f,
+        }
+    }
+}
+
+impl<I, K, F, B> Iterator for MapIters<I, K, F>
+where
+    I: Iterator,
+    K: Iterator,
+    F: FnMut(&mut I, &mut K) -> Option<B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.f)(&mut self.iter_self, &mut self.iter_other)
+    }
+}
+
+/// Returns the offset of `value` from `min`, which must not be greater than `value`.
+#[inline]
+pub(crate) fn int_offset<T: NumCast>(min: T, value: T) -> u128 {
+    match (min.to_u128(), value.to_u128()) {
+        (Some(min), Some(value)) => value - min,
+        // A negative minimum, so both integers are of a signed type that fits in `i128`.
+        _ => {
+            let min = min.to_i128().expect("signed integers fit in i128");
+            let value = value.to_i128().expect("signed integers fit in i128");
+            value.wrapping_sub(min) as u128
+        }
+    }
+}
+
+/// Returns the greatest common divisor of `a` and `b`, `None` if it is the absolute value of
+/// the minimum of a signed type, which is not representable.
+#[inline]
+pub(crate) fn checked_gcd<T: Integer + CheckedSub + Copy>(a: T, b: T) -> Option<T> {
+    // The minimum is the only value without a negation, the GCD is its absolute value only
+    // if each operand is either zero or the minimum.
+    let is_min = |value: T| value < T::zero() && T::zero().checked_sub(&value).is_none();
+    let zero_or_min = |value: T| value.is_zero() || is_min(value);
+    if (is_min(a) || is_min(b)) && zero_or_min(a) && zero_or_min(b) {
+        return None;
+    }
+    Some(gcd(a, b))
+}
+
+/// Finds the non-negative GCD of two integers with the Bézout coefficients, `None` if a value
+/// is not representable in the type.
+#[cfg(feature = "alloc")]
+pub(crate) fn checked_extended_gcd<T>(a: T, b: T) -> Option<(T, T, T)>
+where
+    T: Integer + CheckedMul + CheckedRem + CheckedSub + Copy,
+{
+    let (mut r0, mut r1) = (a, b);
+    let (mut s0, mut s1) = (T::one(), T::zero());
+    let (mut t0, mut t1) = (T::zero(), T::one());
+    while !r1.is_zero() {
+        // Only `MIN % -1` overflows, its remainder is zero and the last coefficients are final.
+        let rem = r0.checked_rem(&r1).unwrap_or_else(T::zero);
+        if rem.is_zero() {
+            (r0, s0, t0) = (r1, s1, t1);
+            break;
+        }
+        let quot = r0 / r1;
+        (s0, s1) = (s1, s0.checked_sub(&quot.checked_mul(&s1)?)?);
+        (t0, t1) = (t1, t0.checked_sub(&quot.checked_mul(&t1)?)?);
+        (r0, r1) = (r1, rem);
+    }
+
+    if r0 < T::zero() {
+        let neg = |value: T| T::zero().checked_sub(&value);
+        return Some((neg(r0)?, neg(s0)?, neg(t0)?));
+    }
+    Some((r0, s0, t0))
+}
+
+/// An iterator that yields the ranges of integers missing between sorted unique integers.
+#[derive(Debug, Clone)]
+pub struct MissingRanges<I: Iterator> {
+    iter: I,
+    prev: Option<I::Item>,
+}
+
+impl<I: Iterator> MissingRanges<I> {
+    #[inline]
+    pub(super) fn new(iter: I) -> MissingRanges<I> {
+        MissingRanges { iter, prev: None }
+    }
+}
+
+impl<I> Iterator for MissingRanges<I>
+where
+    I: Iterator,
+    I::Item: PrimInt,
+{
+    type Item = RangeInclusive<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next = self.iter.next()?;
+            let Some(prev) = self.prev.replace(next) else {
+                continue;
+            };
+            // `prev < next` guarantees that neither bound of the gap overflows.
+            if prev < next && prev + I::Item::one() < next {
+                return Some(prev + I::Item::one()..=next - I::Item::one());
+            }
         }
-        let mut elem = self.iter.next()?;
-        for _ in 1..self.step {
-            if let Some(e) = self.iter.next() {
-                elem = e;
-            } else {
+    }
+}
+
+impl<I> FusedIterator for MissingRanges<I>
+where
+    I: FusedIterator,
+    I::Item: PrimInt,
+{
+}
+
+/// An iterator that yields the ranges of integers of a domain missing from sorted unique integers.
+#[derive(Debug, Clone)]
+pub struct MissingRangesIn<I: Iterator> {
+    iter: I,
+    next: Option<I::Item>,
+    end: I::Item,
+}
+
+#[cfg(feature = "alloc")]
+impl<I> MissingRangesIn<I>
+where
+    I: Iterator,
+    I::Item: PrimInt,
+{
+    #[inline]
+    pub(super) fn new<R: RangeBounds<I::Item>>(iter: I, range: R) -> MissingRangesIn<I> {
+        match inclusive_bounds(range) {
+            Some((start, end)) => MissingRangesIn {
+                iter,
+                next: Some(start),
+                end,
+            },
+            None => MissingRangesIn {
+                iter,
+                next: None,
+                end: I::Item::zero(),
+            },
+        }
+    }
+}
+
+/// Converts range bounds to inclusive bounds, `None` if the range is empty.
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn inclusive_bounds<T: PrimInt, R: RangeBounds<T>>(range: R) -> Option<(T, T)> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(&T::one())?,
+        Bound::Unbounded => T::min_value(),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.checked_sub(&T::one())?,
+        Bound::Unbounded => T::max_value(),
+    };
+
+    (start <= end).then_some((start, end))
+}
+
+impl<I> Iterator for MissingRangesIn<I>
+where
+    I: Iterator,
+    I::Item: PrimInt,
+{
+    type Item = RangeInclusive<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next = self.next?;
+            match self.iter.next() {
+                Some(elem) if elem < next => continue,
+                Some(elem) if elem <= self.end => {
+                    self.next = (elem < self.end).then(|| elem + I::Item::one());
+                    if next < elem {
+                        return Some(next..=elem - I::Item::one());
+                    }
+                }
+                _ => {
+                    self.next = None;
+                    return Some(next..=self.end);
+                }
+            }
+        }
+    }
+}
+
+impl<I> FusedIterator for MissingRangesIn<I>
+where
+    I: Iterator,
+    I::Item: PrimInt,
+{
+}
+
+/// An iterator that yields sorted unique integers outside a domain.
+#[derive(Debug, Clone)]
+pub struct UnexpectedIntegersIn<I: Iterator> {
+    iter: I,
+    bounds: Option<(I::Item, I::Item)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I> UnexpectedIntegersIn<I>
+where
+    I: Iterator,
+    I::Item: PrimInt,
+{
+    #[inline]
+    pub(super) fn new<R: RangeBounds<I::Item>>(iter: I, range: R) -> UnexpectedIntegersIn<I> {
+        UnexpectedIntegersIn {
+            iter,
+            bounds: inclusive_bounds(range),
+        }
+    }
+}
+
+impl<I> UnexpectedIntegersIn<I>
+where
+    I: Iterator,
+    I::Item: PrimInt,
+{
+    #[inline]
+    fn is_unexpected(&self, elem: &I::Item) -> bool {
+        self.bounds
+            .map_or(true, |(start, end)| *elem < start || end < *elem)
+    }
+}
+
+impl<I> Iterator for UnexpectedIntegersIn<I>
+where
+    I: Iterator,
+    I::Item: PrimInt,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let elem = self.iter.next()?;
+            if self.is_unexpected(&elem) {
                 return Some(elem);
             }
         }
-        Some(elem)
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (lower, upper) = self.iter.size_hint();
-        let div = |x: usize| {
-            if x == 0 {
-                0
-            } else {
-                (if (x - 1).wrapping_rem(self.step) == 0 {
-                    1
-                } else {
-                    2
-                }) + (x - 1) / self.step
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I> DoubleEndedIterator for UnexpectedIntegersIn<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: PrimInt,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let elem = self.iter.next_back()?;
+            if self.is_unexpected(&elem) {
+                return Some(elem);
             }
-        };
+        }
+    }
+}
 
-        (div(lower), upper.map(div))
+impl<I> FusedIterator for UnexpectedIntegersIn<I>
+where
+    I: FusedIterator,
+    I::Item: PrimInt,
+{
+}
+
+/// An iterator that yields the integers of inclusive ranges one by one.
+#[derive(Debug, Clone)]
+pub struct ExpandRanges<R: Iterator> {
+    ranges: R,
+    range: Option<R::Item>,
+}
+
+impl<R: Iterator> ExpandRanges<R> {
+    #[inline]
+    pub(super) fn new(ranges: R) -> ExpandRanges<R> {
+        ExpandRanges {
+            ranges,
+            range: None,
+        }
     }
 }
 
-impl<I: Iterator> InclusiveStepBy<I> {
+impl<R, T> Iterator for ExpandRanges<R>
+where
+    R: Iterator<Item = RangeInclusive<T>>,
+    T: PrimInt,
+{
+    type Item = T;
+
     #[inline]
-    pub(super) fn new(iter: I, step: usize) -> InclusiveStepBy<I> {
-        InclusiveStepBy {
-            iter,
-            firs_step: true,
-            step,
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = loop {
+            match self.range.take() {
+                Some(range) => break range,
+                None => match self.ranges.next()? {
+                    range if range.is_empty() => continue,
+                    range => break range,
+                },
+            }
+        }
+        .into_inner();
+        self.range = (start < end).then(|| start + T::one()..=end);
+        Some(start)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.range {
+            None => (0, None),
+            Some(range) => {
+                let len = usize::try_from(int_offset(*range.start(), *range.end())).ok();
+                (
+                    len.and_then(|len| len.checked_add(1)).unwrap_or(usize::MAX),
+                    None,
+                )
+            }
         }
     }
 }
 
-impl<I> ExactSizeIterator for InclusiveStepBy<I> where I: ExactSizeIterator {}
+impl<R, T> FusedIterator for ExpandRanges<R>
+where
+    R: FusedIterator<Item = RangeInclusive<T>>,
+    T: PrimInt,
+{
+}
 
-/// An iterator that yields three elements each iteration.
-#[derive(Clone)]
-pub struct MapByThree<I, F> {
-    iter: I,
-    f: F,
+/// An iterator that yields the integers of missing ranges one by one.
+pub struct MissingIntegers<R: Iterator> {
+    integers: ExpandRanges<R>,
 }
 
-impl<I, F> fmt::Debug for MapByThree<I, F>
+impl<R> Clone for MissingIntegers<R>
 where
-    I: fmt::Debug,
+    R: Iterator + Clone,
+    R::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        MissingIntegers {
+            integers: self.integers.clone(),
+        }
+    }
+}
+
+impl<R> fmt::Debug for MissingIntegers<R>
+where
+    R: Iterator + fmt::Debug,
+    R::Item: fmt::Debug,
 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct("MapByThree")
-            .field("iter", &self.iter)
-            .field("f", &format_args!("{:p}", &self.f))
+        fmt.debug_struct("MissingIntegers")
+            .field("integers", &self.integers)
             .finish()
     }
 }
 
-impl<I, F> MapByThree<I, F> {
+impl<R: Iterator> MissingIntegers<R> {
     #[inline]
-    pub(super) fn new(iter: I, f: F) -> MapByThree<I, F> {
-        MapByThree { iter, f }
+    pub(super) fn new(ranges: R) -> MissingIntegers<R> {
+        MissingIntegers {
+            integers: ExpandRanges::new(ranges),
+        }
     }
 }
 
-impl<B, F, I> Iterator for MapByThree<I, F>
+impl<R, T> Iterator for MissingIntegers<R>
+where
+    R: Iterator<Item = RangeInclusive<T>>,
+    T: PrimInt,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.integers.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.integers.size_hint()
+    }
+}
+
+impl<R, T> FusedIterator for MissingIntegers<R>
+where
+    R: FusedIterator<Item = RangeInclusive<T>>,
+    T: PrimInt,
+{
+}
+
+/// An iterator that collapses sorted integers into ranges of consecutive integers.
+#[derive(Debug, Clone)]
+pub struct ConsecutiveRanges<I: Iterator> {
+    iter: I,
+    start: Option<I::Item>,
+}
+
+impl<I: Iterator> ConsecutiveRanges<I> {
+    #[inline]
+    pub(super) fn new(iter: I) -> ConsecutiveRanges<I> {
+        ConsecutiveRanges { iter, start: None }
+    }
+}
+
+impl<I> Iterator for ConsecutiveRanges<I>
 where
     I: Iterator,
-    F: FnMut(I::Item, I::Item, I::Item) -> B,
+    I::Item: PrimInt,
 {
-    type Item = B;
+    type Item = RangeInclusive<I::Item>;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        match self.iter.next() {
-            None => None,
-            Some(a) => match self.iter.next() {
-                None => None,
-                Some(b) => match self.iter.next() {
-                    None => None,
-                    Some(c) => Some((self.f)(a, b, c)),
-                },
-            },
+        let start = self.start.take().or_else(|| self.iter.next())?;
+        let mut end = start;
+        for elem in self.iter.by_ref() {
+            // Equal elements extend the range, `end < elem` guards the increment from overflow.
+            if elem == end || (end < elem && end + I::Item::one() == elem) {
+                end = elem;
+            } else {
+                self.start = Some(elem);
+                break;
+            }
         }
+        Some(start..=end)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let start = self.start.is_some() as usize;
+
+        (
+            lower.min(1).max(start),
+            upper.and_then(|upper| upper.checked_add(start)),
+        )
+    }
+}
+
+impl<I> FusedIterator for ConsecutiveRanges<I>
+where
+    I: FusedIterator,
+    I::Item: PrimInt,
+{
+}
+
+/// An iterator that yields consecutive equal elements with the length of their run.
+#[derive(Debug, Clone)]
+pub struct Runs<I: Iterator> {
+    iter: I,
+    item: Option<I::Item>,
+}
+
+impl<I: Iterator> Runs<I> {
+    #[inline]
+    pub(super) fn new(iter: I) -> Runs<I> {
+        Runs { iter, item: None }
     }
 }
 
-/// An iterator that yields two elements each iteration.
-#[derive(Clone)]
-pub struct MapByTwo<I, F> {
-    iter: I,
-    f: F,
+impl<I> Iterator for Runs<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.item.take().or_else(|| self.iter.next())?;
+        let mut count = 1;
+        for elem in self.iter.by_ref() {
+            if elem == item {
+                count += 1;
+            } else {
+                self.item = Some(elem);
+                break;
+            }
+        }
+        Some((item, count))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let item = self.item.is_some() as usize;
+
+        (
+            lower.min(1).max(item),
+            upper.and_then(|upper| upper.checked_add(item)),
+        )
+    }
 }
 
-impl<I, F> fmt::Debug for MapByTwo<I, F>
+impl<I> FusedIterator for Runs<I>
 where
-    I: fmt::Debug,
+    I: FusedIterator,
+    I::Item: PartialEq,
 {
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct("MapByTwo")
-            .field("iter", &self.iter)
-            .field("f", &format_args!("{:p}", &self.f))
-            .finish()
-    }
 }
 
-impl<I, F> MapByTwo<I, F> {
+/// An iterator that repeats each element by the length of its run.
+#[derive(Debug, Clone)]
+pub struct ExpandRuns<I, T> {
+    iter: I,
+    run: Option<(T, usize)>,
+}
+
+impl<I, T> ExpandRuns<I, T> {
     #[inline]
-    pub(super) fn new(iter: I, f: F) -> MapByTwo<I, F> {
-        MapByTwo { iter, f }
+    pub(super) fn new(iter: I) -> ExpandRuns<I, T> {
+        ExpandRuns { iter, run: None }
     }
 }
 
-impl<B, F, I> Iterator for MapByTwo<I, F>
+impl<I, T> Iterator for ExpandRuns<I, T>
 where
-    I: Iterator,
-    F: FnMut(I::Item, I::Item) -> B,
+    I: Iterator<Item = (T, usize)>,
+    T: Clone,
 {
-    type Item = B;
+    type Item = T;
 
     #[inline]
-    fn next(&mut self) -> Option<B> {
-        match self.iter.next() {
-            None => None,
-            Some(x) => match self.iter.next() {
-                None => None,
-                Some(y) => Some((self.f)(x, y)),
-            },
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (item, count) = match self.run.take() {
+                Some(run) => run,
+                None => self.iter.next()?,
+            };
+            match count {
+                0 => continue,
+                1 => return Some(item),
+                count => {
+                    self.run = Some((item.clone(), count - 1));
+                    return Some(item);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.run.as_ref().map_or(0, |&(_, count)| count);
+        match self.iter.size_hint() {
+            (_, Some(0)) => (count, Some(count)),
+            _ => (count, None),
         }
     }
 }
 
-/// Iterator adapter which provides two iterators for its closure at each iteration.
-#[derive(Clone)]
-pub struct MapIters<I, K, F> {
-    iter_self: I,
-    iter_other: K,
-    f: F,
+impl<I, T> FusedIterator for ExpandRuns<I, T>
+where
+    I: FusedIterator<Item = (T, usize)>,
+    T: Clone,
+{
 }
 
-impl<I, K, F> fmt::Debug for MapIters<I, K, F>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SetOp {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// An iterator that merges two sorted iterators with a set operation.
+///
+/// This struct is created by the [`union_sorted`], [`intersect_sorted`], [`difference_sorted`]
+/// and [`symmetric_difference_sorted`] methods.
+///
+/// [`union_sorted`]: crate::IterExtd::union_sorted
+/// [`intersect_sorted`]: crate::IterExtd::intersect_sorted
+/// [`difference_sorted`]: crate::IterExtd::difference_sorted
+/// [`symmetric_difference_sorted`]: crate::IterExtd::symmetric_difference_sorted
+pub struct SetOpSorted<I: Iterator, J: Iterator> {
+    self_iter: Peekable<I>,
+    other_iter: Peekable<J>,
+    op: SetOp,
+}
+
+impl<I, J> Clone for SetOpSorted<I, J>
 where
-    I: fmt::Debug,
-    K: fmt::Debug,
+    I: Iterator + Clone,
+    J: Iterator + Clone,
+    I::Item: Clone,
+    J::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        SetOpSorted {
+            self_iter: self.self_iter.clone(),
+            other_iter: self.other_iter.clone(),
+            op: self.op,
+        }
+    }
+}
+
+impl<I, J> fmt::Debug for SetOpSorted<I, J>
+where
+    I: Iterator + fmt::Debug,
+    J: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    J::Item: fmt::Debug,
 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct("MapIters")
-            .field("iter_self", &self.iter_self)
-            .field("iter_other", &self.iter_other)
-            .field("f", &format_args!("{:p}", &self.f))
+        fmt.debug_struct("SetOpSorted")
+            .field("self_iter", &self.self_iter)
+            .field("other_iter", &self.other_iter)
+            .field("op", &self.op)
             .finish()
     }
 }
 
-impl<I, K, F, B> MapIters<I, K, F>
+impl<I, J> SetOpSorted<I, J>
 where
     I: Iterator,
-    K: Iterator,
-    F: FnMut(&mut I, &mut K) -> Option<B>,
+    J: Iterator<Item = I::Item>,
 {
     #[inline]
-    pub(super) fn new(iter_self: I, iter_other: K, f: F) -> MapIters<I, K, F> {
-        MapIters {
-            iter_self,
-            iter_other,
-            f,
+    pub(super) fn new(self_iter: I, other_iter: J, op: SetOp) -> SetOpSorted<I, J> {
+        SetOpSorted {
+            self_iter: self_iter.peekable(),
+            other_iter: other_iter.peekable(),
+            op,
         }
     }
 }
 
-impl<I, K, F, B> Iterator for MapIters<I, K, F>
+/// Takes the next element, checking in debug mode that it does not exceed the following one.
+#[inline]
+fn next_sorted<I>(iter: &mut Peekable<I>) -> Option<I::Item>
 where
     I: Iterator,
-    K: Iterator,
-    F: FnMut(&mut I, &mut K) -> Option<B>,
+    I::Item: Ord,
 {
-    type Item = B;
-
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        (self.f)(&mut self.iter_self, &mut self.iter_other)
+    let elem = iter.next()?;
+    if cfg!(debug_assertions) {
+        if let Some(next) = iter.peek() {
+            assert!(elem <= *next, "the iterator must be sorted");
+        }
     }
+    Some(elem)
 }
 
-/// An iterator adapter that returns missing numbers.
-#[allow(missing_debug_implementations)]
-pub struct MissingIntegers<I> {
-    pub(crate) iter: IntoOnes,
-    pub(crate) min: usize,
-    pub(crate) _phantom: PhantomData<I>,
-}
+impl<I, J> Iterator for SetOpSorted<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use core::cmp::Ordering::{Equal, Greater, Less};
+
+        loop {
+            let order = match (self.self_iter.peek(), self.other_iter.peek()) {
+                (Some(self_elem), Some(other_elem)) => self_elem.cmp(other_elem),
+                (Some(_), None) => Less,
+                (None, Some(_)) => Greater,
+                (None, None) => return None,
+            };
+
+            match (order, self.op) {
+                (Less, SetOp::Intersection) if self.other_iter.peek().is_none() => return None,
+                (Greater, SetOp::Intersection | SetOp::Difference)
+                    if self.self_iter.peek().is_none() =>
+                {
+                    return None
+                }
+                (Less, SetOp::Intersection) => {
+                    next_sorted(&mut self.self_iter);
+                }
+                (Less, _) => return next_sorted(&mut self.self_iter),
+                (Greater, SetOp::Union | SetOp::SymmetricDifference) => {
+                    return next_sorted(&mut self.other_iter)
+                }
+                (Greater, _) => {
+                    next_sorted(&mut self.other_iter);
+                }
+                (Equal, op) => {
+                    next_sorted(&mut self.other_iter);
+                    let elem = next_sorted(&mut self.self_iter);
+                    if let SetOp::Union | SetOp::Intersection = op {
+                        return elem;
+                    }
+                }
+            }
+        }
+    }
 
-impl<I> MissingIntegers<I> {
     #[inline]
-    pub(crate) fn default() -> Self {
-        MissingIntegers {
-            iter: FixedBitSet::new().into_ones(),
-            min: 0,
-            _phantom: PhantomData,
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (self_lower, self_upper) = self.self_iter.size_hint();
+        let (other_lower, other_upper) = self.other_iter.size_hint();
+        let sum_upper = self_upper.and_then(|upper| upper.checked_add(other_upper?));
+
+        match self.op {
+            SetOp::Union => (self_lower.max(other_lower), sum_upper),
+            SetOp::Intersection => (
+                0,
+                match (self_upper, other_upper) {
+                    (Some(self_upper), Some(other_upper)) => Some(self_upper.min(other_upper)),
+                    (upper, None) | (None, upper) => upper,
+                },
+            ),
+            SetOp::Difference => (
+                other_upper.map_or(0, |upper| self_lower.saturating_sub(upper)),
+                self_upper,
+            ),
+            SetOp::SymmetricDifference => (0, sum_upper),
         }
     }
 }
 
-impl<I> Iterator for MissingIntegers<I>
+impl<I, J> FusedIterator for SetOpSorted<I, J>
 where
-    I: Iterator,
-    <I as Iterator>::Item: Deref,
-    <<I as Iterator>::Item as Deref>::Target: Sized + TryFromByAdd<usize>,
+    I: FusedIterator,
+    J: FusedIterator<Item = I::Item>,
+    I::Item: Ord,
 {
-    type Item = <<I as Iterator>::Item as Deref>::Target;
-
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(|x| {
-            <<<I as Iterator>::Item as Deref>::Target as TryFromByAdd<usize>>::try_from_by_add(
-                x + self.min,
-            )
-        })?
-    }
 }
 
 /// An iterator adapter that preserves the element of the last iteration.
@@ -772,6 +2557,208 @@ where
     }
 }
 
+/// The elements collected before the iterator ran out, returned by
+/// [`try_collect_array`](crate::IterExtd::try_collect_array).
+///
+/// It is an iterator over the collected elements and drops the ones that were not taken.
+pub struct PartialArray<T, const N: usize> {
+    arr: [MaybeUninit<T>; N],
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        PartialArray {
+            arr: unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() },
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Fills the array from the iterator, returning the partial array if the iterator runs out.
+    #[inline]
+    pub(crate) fn fill<I>(mut self, iter: &mut I) -> Result<[T; N], Self>
+    where
+        I: Iterator<Item = T>,
+    {
+        while self.end < N {
+            match iter.next() {
+                Some(elem) => {
+                    self.arr[self.end] = MaybeUninit::new(elem);
+                    self.end += 1;
+                }
+                None => return Err(self),
+            }
+        }
+
+        self.into_array()
+    }
+
+    /// Fills the first `count` slots of the array from the back of the iterator,
+    /// keeping the order of the elements.
+    #[inline]
+    pub(crate) fn fill_back<I>(mut self, iter: &mut I, count: usize) -> Self
+    where
+        I: DoubleEndedIterator<Item = T>,
+    {
+        self.start = count;
+        self.end = count;
+        while self.start > 0 {
+            match iter.next_back() {
+                Some(elem) => {
+                    self.start -= 1;
+                    self.arr[self.start] = MaybeUninit::new(elem);
+                }
+                None => break,
+            }
+        }
+
+        self
+    }
+
+    /// Appends an element to the back, returning it back if the array is full.
+    #[inline]
+    pub(crate) fn push(&mut self, elem: T) -> Result<(), T> {
+        if self.end == N {
+            return Err(elem);
+        }
+        self.arr[self.end] = MaybeUninit::new(elem);
+        self.end += 1;
+        Ok(())
+    }
+
+    /// Converts a completely filled partial array into an array.
+    #[inline]
+    pub(crate) fn into_array(mut self) -> Result<[T; N], Self> {
+        if self.start != 0 || self.end != N {
+            return Err(self);
+        }
+
+        self.end = 0;
+        Ok(unsafe { ptr::read(&self.arr).map(|elem| elem.assume_init()) })
+    }
+
+    /// Returns the number of elements that are still in the partial array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the partial array holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the elements as a slice.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::IterExtd;
+    ///
+    /// let partial = [1, 2].into_iter().try_collect_array::<3>().unwrap_err();
+    /// assert_eq!(partial.as_slice(), &[1, 2]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { &*(&self.arr[self.start..self.end] as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    /// Returns the elements as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { &mut *(&mut self.arr[self.start..self.end] as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for PartialArray<T, N> {
+    fn clone(&self) -> Self {
+        let mut new = PartialArray::new();
+        for elem in self.as_slice() {
+            new.arr[new.end] = MaybeUninit::new(elem.clone());
+            new.end += 1;
+        }
+        new
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for PartialArray<T, N> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("PartialArray")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for PartialArray<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for PartialArray<T, N> {}
+
+impl<T, const N: usize> fmt::Display for PartialArray<T, N> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "iterator yielded {} of {} elements", self.len(), N)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T: fmt::Debug, const N: usize> std::error::Error for PartialArray<T, N> {}
+
+impl<T, const N: usize> From<PartialArray<T, N>> for IterExtdError {
+    #[inline]
+    fn from(_: PartialArray<T, N>) -> Self {
+        IterExtdError::TooShort
+    }
+}
+
+impl<T, const N: usize> Iterator for PartialArray<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(unsafe { self.arr[self.start - 1].assume_init_read() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for PartialArray<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(unsafe { self.arr[self.end].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for PartialArray<T, N> {}
+
+impl<T, const N: usize> FusedIterator for PartialArray<T, N> {}
+
 /// An iterator that converts a [`Range`] at each iteration to a tuple.
 #[derive(Debug, Clone)]
 pub struct RangeToTup<I> {
@@ -1063,54 +3050,164 @@ pub struct TupleMut<'a, T: 'a, const N: usize> {
     pub(crate) _unused: PhantomData<&'a mut T>,
 }
 
+/// Strategies used by the [`unique_sorted`] adapter.
+///
+/// [`unique_sorted`]: crate::IterExtd::unique_sorted
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UniqueSortedStrategy {
+    /// Marks the integers in a bit set spanning the range from minimum to maximum.
+    Bitset,
+    /// Collects the integers into a vector, then sorts and deduplicates it.
+    SortDedup,
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) enum UniqueSortedInner<T> {
+    Bitset { ones: IntoOnes, min: u128 },
+    SortDedup(vec::IntoIter<T>),
+}
+
+/// The largest range of integers, in bits, that [`UniqueSortedStrategy::Bitset`] allocates.
+#[cfg(feature = "alloc")]
+const BITSET_MAX_LEN: u128 = 1 << 32;
+
+/// An iterator that yields unique sorted integers.
+///
+/// The `T` parameter is the integer type and defaults to the target of the item type of `I`.
+#[cfg(feature = "alloc")]
 #[allow(missing_debug_implementations)]
-pub struct UniqueSorted<I> {
-    pub(crate) iter: IntoOnes,
-    pub(crate) min: usize,
+pub struct UniqueSorted<I, T = <<I as Iterator>::Item as Deref>::Target> {
+    pub(crate) iter: UniqueSortedInner<T>,
     pub(crate) _phantom: PhantomData<I>,
 }
 
-impl<I> UniqueSorted<I> {
-    #[inline]
-    pub(crate) fn default() -> Self {
+#[cfg(feature = "alloc")]
+impl<I, T> UniqueSorted<I, T>
+where
+    I: Iterator + Clone,
+    I::Item: Deref<Target = T>,
+    T: Copy + Ord,
+    u128: TryFromByAdd<T>,
+{
+    pub(crate) fn new(iter: I, strategy: Option<UniqueSortedStrategy>) -> Self {
+        // All integer types map onto `u128` preserving the order, signed types by an offset.
+        let to_key = |x: I::Item| u128::try_from_by_add(*x);
+
+        let bitset_span = match strategy {
+            Some(UniqueSortedStrategy::SortDedup) => None,
+            _ => {
+                let (count, min_max) =
+                    iter.clone()
+                        .filter_map(to_key)
+                        .fold((0u128, None), |(count, min_max), key| match min_max {
+                            None => (count + 1, Some((key, key))),
+                            Some((min, max)) => (count + 1, Some((key.min(min), key.max(max)))),
+                        });
+                // The bit set is chosen if it does not take more memory than the sorted integers,
+                // an explicitly requested one is bounded by `BITSET_MAX_LEN`. Both must be
+                // addressable by `usize`, which is narrower than `BITSET_MAX_LEN` on 32-bit targets.
+                let item_bits = (core::mem::size_of::<T>() as u128 * 8).max(1);
+                min_max.filter(|(min, max)| {
+                    max - min < usize::MAX as u128
+                        && match strategy {
+                            Some(_) => max - min < BITSET_MAX_LEN,
+                            None => (max - min) / item_bits <= count,
+                        }
+                })
+            }
+        };
+
+        let iter = match bitset_span {
+            Some((min, max)) => {
+                let mut bitset = FixedBitSet::with_capacity((max - min) as usize + 1);
+                iter.filter_map(to_key)
+                    .for_each(|key| bitset.insert((key - min) as usize));
+                UniqueSortedInner::Bitset {
+                    ones: bitset.into_ones(),
+                    min,
+                }
+            }
+            None => {
+                let mut items = iter.map(|x| *x).collect::<Vec<_>>();
+                items.sort_unstable();
+                items.dedup();
+                UniqueSortedInner::SortDedup(items.into_iter())
+            }
+        };
+
         UniqueSorted {
-            iter: FixedBitSet::new().into_ones(),
-            min: 0,
+            iter,
             _phantom: PhantomData,
         }
     }
 }
 
-impl<I> Iterator for UniqueSorted<I>
+#[cfg(feature = "alloc")]
+impl<I, T> UniqueSorted<I, T> {
+    /// Returns the strategy used to sort and deduplicate the integers.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iterextd::{IterExtd, UniqueSortedStrategy};
+    ///
+    /// let iter = [3u64, 1, 2, 3].iter().unique_sorted();
+    /// assert_eq!(iter.strategy(), UniqueSortedStrategy::Bitset);
+    ///
+    /// let iter = [u64::MAX, 0, u64::MAX].iter().unique_sorted();
+    /// assert_eq!(iter.strategy(), UniqueSortedStrategy::SortDedup);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, u64::MAX]);
+    /// ```
+    #[inline]
+    pub fn strategy(&self) -> UniqueSortedStrategy {
+        match self.iter {
+            UniqueSortedInner::Bitset { .. } => UniqueSortedStrategy::Bitset,
+            UniqueSortedInner::SortDedup(_) => UniqueSortedStrategy::SortDedup,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T> Iterator for UniqueSorted<I, T>
 where
-    I: Iterator,
-    <I as Iterator>::Item: Deref,
-    <<I as Iterator>::Item as Deref>::Target: Sized + TryFromByAdd<usize>,
+    T: TryFromByAdd<u128>,
 {
-    type Item = <<I as Iterator>::Item as Deref>::Target;
+    type Item = T;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(|x| {
-            <<<I as Iterator>::Item as Deref>::Target as TryFromByAdd<usize>>::try_from_by_add(
-                x + self.min,
-            )
-        })?
+        match &mut self.iter {
+            UniqueSortedInner::Bitset { ones, min } => {
+                T::try_from_by_add(*min + ones.next()? as u128)
+            }
+            UniqueSortedInner::SortDedup(iter) => iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.iter {
+            UniqueSortedInner::Bitset { ones, .. } => ones.size_hint(),
+            UniqueSortedInner::SortDedup(iter) => iter.size_hint(),
+        }
     }
 }
 
-impl<I> DoubleEndedIterator for UniqueSorted<I>
+#[cfg(feature = "alloc")]
+impl<I, T> DoubleEndedIterator for UniqueSorted<I, T>
 where
-    I: DoubleEndedIterator,
-    <I as Iterator>::Item: Deref,
-    <<I as Iterator>::Item as Deref>::Target: Sized + TryFromByAdd<usize>,
+    T: TryFromByAdd<u128>,
 {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter.next_back().map(|x| {
-            <<<I as Iterator>::Item as Deref>::Target as TryFromByAdd<usize>>::try_from_by_add(
-                x + self.min,
-            )
-        })?
+        match &mut self.iter {
+            UniqueSortedInner::Bitset { ones, min } => {
+                T::try_from_by_add(*min + ones.next_back()? as u128)
+            }
+            UniqueSortedInner::SortDedup(iter) => iter.next_back(),
+        }
     }
 }
@@ -1,5 +1,6 @@
 use core::iter;
 use iterextd::IterExtd;
+use std::rc::Rc;
 use trybuild::TestCases;
 
 #[test]
@@ -114,7 +115,7 @@ fn test_array_chunks() {
     let iter = vec.iter().arr_chunks::<1>();
     assert_eq!(
         format!("{:?}", iter),
-        "ArrChunks { iter: Iter([\"one\"]) }".to_string()
+        "ArrChunks { iter: Iter([\"one\"]), remainder: None }".to_string()
     );
 }
 
@@ -179,6 +180,132 @@ fn test_arr_chunks_size_hint() {
     assert_eq!(it.size_hint(), (0, None));
 }
 
+#[test]
+fn test_arr_chunks_remainder() {
+    let mut iter = (0..11).arr_chunks::<4>();
+    assert!(iter.clone().into_remainder().is_none());
+    assert_eq!(iter.next(), Some([0, 1, 2, 3]));
+    assert_eq!(iter.next(), Some([4, 5, 6, 7]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    let rem = iter.into_remainder().unwrap();
+    assert_eq!(rem.as_slice(), &[8, 9, 10]);
+    assert_eq!(rem.collect::<Vec<_>>(), vec![8, 9, 10]);
+
+    let mut iter = (0..8).arr_chunks::<4>();
+    assert_eq!(iter.by_ref().count(), 2);
+    let mut iter = (0..8).arr_chunks::<4>();
+    iter.by_ref().for_each(drop);
+    assert!(iter.into_remainder().unwrap().is_empty());
+
+    let mut iter = (0..5).arr_chunks::<3>();
+    iter.by_ref().for_each(drop);
+    assert_eq!(
+        format!("{:?}", iter),
+        "ArrChunks { iter: 5..5, remainder: Some(PartialArray([3, 4])) }"
+    );
+}
+
+#[test]
+fn test_arr_chunks_remainder_drop() {
+    let rc = Rc::new(());
+    let mut iter = iter::repeat(rc.clone()).take(5).arr_chunks::<3>();
+    let chunk = iter.next().unwrap();
+    assert_eq!(Rc::strong_count(&rc), 5);
+    drop(chunk);
+    assert_eq!(iter.next(), None);
+    assert_eq!(Rc::strong_count(&rc), 4);
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let mut iter = vec![rc.clone(), rc.clone()].into_iter().arr_chunks::<3>();
+    assert_eq!(iter.next(), None);
+    let rem = iter.into_remainder().unwrap();
+    assert_eq!(rem.len(), 2);
+    drop(rem);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_arr_chunks_double_ended() {
+    let iter = (0..11).arr_chunks::<3>();
+    assert_eq!(iter.rev().collect::<Vec<_>>(), vec![[6, 7, 8], [3, 4, 5], [0, 1, 2]]);
+
+    let mut iter = (0..11).arr_chunks::<3>();
+    assert_eq!(iter.next_back(), Some([6, 7, 8]));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some([0, 1, 2]));
+    assert_eq!(iter.next_back(), Some([3, 4, 5]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.into_remainder().unwrap().as_slice(), &[9, 10]);
+
+    let mut iter = (0..9).arr_chunks::<3>();
+    assert_eq!(iter.next(), Some([0, 1, 2]));
+    assert_eq!(iter.next_back(), Some([6, 7, 8]));
+    assert_eq!(iter.next(), Some([3, 4, 5]));
+    assert_eq!(iter.next_back(), None);
+    assert!(iter.into_remainder().unwrap().is_empty());
+
+    let vec = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let mut iter = vec.into_iter().arr_chunks::<2>();
+    assert_eq!(iter.next_back(), Some(["a".to_string(), "b".to_string()]));
+    assert_eq!(iter.into_remainder().unwrap().as_slice(), &["c".to_string()]);
+}
+
+#[test]
+fn test_array_windows() {
+    let vec = (0..5).array_windows::<2>().collect::<Vec<_>>();
+    assert_eq!(vec, vec![[0, 1], [1, 2], [2, 3], [3, 4]]);
+
+    let vec = (0..5).array_windows::<5>().collect::<Vec<_>>();
+    assert_eq!(vec, vec![[0, 1, 2, 3, 4]]);
+
+    let vec = (0..5).array_windows::<6>().collect::<Vec<_>>();
+    assert_eq!(vec, Vec::<[i32; 6]>::new());
+
+    let vec = (0..3).array_windows::<1>().collect::<Vec<_>>();
+    assert_eq!(vec, vec![[0], [1], [2]]);
+
+    let words = ["a".to_string(), "b".to_string(), "c".to_string()];
+    let vec = words.iter().cloned().array_windows::<2>().collect::<Vec<_>>();
+    assert_eq!(
+        vec,
+        vec![
+            ["a".to_string(), "b".to_string()],
+            ["b".to_string(), "c".to_string()]
+        ]
+    );
+
+    let vec = "windows".chars().array_windows::<3>().map(String::from_iter).collect::<Vec<_>>();
+    assert_eq!(vec, vec!["win", "ind", "ndo", "dow", "ows"]);
+}
+
+#[test]
+fn test_array_windows_size_hint() {
+    let mut iter = (0..10).array_windows::<4>();
+    assert_eq!(iter.len(), 7);
+    iter.next();
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    iter.nth(4);
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some([6, 7, 8, 9]));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+
+    let iter = (0..2).array_windows::<4>();
+    assert_eq!(iter.len(), 0);
+
+    let iter = (0..).array_windows::<4>();
+    assert_eq!(iter.size_hint(), (usize::MAX - 3, None));
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn test_array_windows_const_zero() {
+    let _ = (0..5).array_windows::<0>();
+}
+
 #[test]
 fn test_collect_array() {
     let arr = [('a', 20u8), ('b', 22), ('c', 122)];
@@ -228,11 +355,11 @@ fn test_collect_array() {
 }
 
 #[test]
-#[should_panic]
-#[ignore = "arr_collect_const"]
 fn test_collect_array_const_zero() {
-    let data = vec![100, 200, 300].into_iter();
-    let _arr = data.collect_array::<0>();
+    let mut data = vec![100, 200, 300].into_iter();
+    let arr = data.by_ref().collect_array::<0>();
+    assert_eq!(arr, [0; 0]);
+    assert_eq!(data.next(), Some(100));
 }
 
 #[test]
@@ -368,7 +495,7 @@ fn test_copied() {
     let iter = vec.iter().arr_chunks::<1>().array_copied();
     assert_eq!(
         &format!("{:?}", iter),
-        "ArrayCopied { iter: ArrChunks { iter: Iter(['c']) } }"
+        "ArrayCopied { iter: ArrChunks { iter: Iter(['c']), remainder: None } }"
     );
 
     let vec: Vec<char> = vec!['c', 'o', 'p', 'i', 'e', 'd'];
@@ -425,7 +552,7 @@ fn test_cloned() {
     let iter = vec.iter().arr_chunks::<1>().array_cloned();
     assert_eq!(
         &format!("{:?}", iter),
-        "ArrayCloned { iter: ArrChunks { iter: Iter(['c']) } }"
+        "ArrayCloned { iter: ArrChunks { iter: Iter(['c']), remainder: None } }"
     );
 
     let vec = vec![vec!["usize"], vec!["isize"], vec!["float"], vec!["typle"]];
@@ -832,6 +959,85 @@ fn test_map_by_three() {
     assert_eq!(vec_left, vec_right);
 }
 
+#[test]
+fn test_map_by_size_hint() {
+    let iter = (0..7).map_by_two(|a, b| a + b);
+    assert_eq!(iter.len(), 3);
+    let iter = (0..7).map_by_three(|a, b, c| a + b + c);
+    assert_eq!(iter.len(), 2);
+    let iter = (0..).take_while(|x| *x < 7).map_by_two(|a, b| a + b);
+    assert_eq!(iter.size_hint(), (0, None));
+}
+
+#[test]
+fn test_map_by() {
+    let iter = (1..11).map_by::<4, _, _>(|arr| arr.iter().sum::<i32>());
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 26]);
+
+    let iter = (1..9).map_by::<4, _, _>(|[a, b, c, d]| (d, c, b, a));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![(4, 3, 2, 1), (8, 7, 6, 5)]);
+
+    let iter = (0..0).map_by::<3, _, _>(|arr| arr);
+    assert_eq!(iter.collect::<Vec<_>>(), Vec::<[i32; 3]>::new());
+
+    let arr = ["one", "two", "three", "four"];
+    let iter = arr.iter().map_by::<2, _, _>(|[a, b]| [b, a]);
+    let iter_cloned = iter.clone();
+    assert_eq!(iter.collect::<Vec<_>>(), iter_cloned.collect::<Vec<_>>());
+}
+
+#[test]
+fn test_map_by_pad() {
+    let mut iter = (1..11).map_by_pad::<4, _, _>(0, |arr| arr);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some([1, 2, 3, 4]));
+    assert_eq!(iter.next(), Some([5, 6, 7, 8]));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some([9, 10, 0, 0]));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let iter = (1..9).map_by_pad::<4, _, _>(0, |arr| arr);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![[1, 2, 3, 4], [5, 6, 7, 8]]);
+
+    let iter = (0..0).map_by_pad::<3, _, _>(0, |arr| arr);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.collect::<Vec<_>>(), Vec::<[i32; 3]>::new());
+
+    let pad = Rc::new(0);
+    let vec = (1..6).map(Rc::new).collect::<Vec<_>>();
+    let iter = vec.into_iter().map_by_pad::<3, _, _>(pad.clone(), |[a, b, c]| *a + *b + *c);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![6, 9]);
+    assert_eq!(Rc::strong_count(&pad), 1);
+}
+
+#[test]
+fn test_map_by_rest() {
+    let mut iter = (1..11).map_by_rest::<4, _, _, _>(|arr| arr.to_vec(), |rest| rest.to_vec());
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(vec![1, 2, 3, 4]));
+    assert_eq!(iter.next(), Some(vec![5, 6, 7, 8]));
+    assert_eq!(iter.next(), Some(vec![9, 10]));
+    assert_eq!(iter.next(), None);
+
+    let iter = (1..9).map_by_rest::<4, _, _, _>(|arr| arr.len(), |rest| rest.len());
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![4, 4]);
+
+    let iter = (1..4).map_by_rest::<4, _, _, _>(|arr| arr.len(), |rest| rest.len());
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![3]);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_map_by_zero() {
+    let _ = (0..4).map_by::<0, _, _>(|arr: [i32; 0]| arr.len());
+}
+
 #[test]
 fn test_consume() {
     let mut arr = [1, 2, 3];
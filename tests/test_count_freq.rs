@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use iterextd::IterExtd;
+    #[cfg(feature = "alloc")]
+    use iterextd::FreqOrder;
+    use iterextd::{IterExtd, IterExtdError};
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_count_freq() {
         let vec = vec![1, 2, 2, 3, 3, 3];
@@ -23,4 +26,58 @@ mod tests {
         let count_freq: Vec<_> = arr.into_iter().count_freq().collect();
         assert_eq!(count_freq, vec![(1..5, 1)]);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_count_freq_sorted() {
+        let s = "Repeated most often";
+        let freqs = s.chars().count_freq_sorted(FreqOrder::Key).collect::<Vec<_>>();
+        assert_eq!(freqs, vec![(' ', 2), ('R', 1), ('a', 1), ('d', 1), ('e', 4), ('f', 1), ('m', 1), ('n', 1), ('o', 2), ('p', 1), ('s', 1), ('t', 3)]);
+
+        let freqs = s.chars().count_freq_sorted(FreqOrder::Count).collect::<Vec<_>>();
+        assert_eq!(freqs, vec![('e', 4), ('t', 3), (' ', 2), ('o', 2), ('R', 1), ('a', 1), ('d', 1), ('f', 1), ('m', 1), ('n', 1), ('p', 1), ('s', 1)]);
+
+        let arr: [u8; 0] = [];
+        assert_eq!(arr.into_iter().count_freq_sorted(FreqOrder::Count).collect::<Vec<_>>(), vec![]);
+
+        let arr = [(3, 5), (1, 2), (3, 5)];
+        let freqs = arr.into_iter().count_freq_sorted(FreqOrder::Key).collect::<Vec<_>>();
+        assert_eq!(freqs, vec![((1, 2), 1), ((3, 5), 2)]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_count_freq_first_seen() {
+        let s = "Repeated most often";
+        let freqs = s.chars().count_freq_first_seen().collect::<Vec<_>>();
+        assert_eq!(freqs, vec![('R', 1), ('e', 4), ('p', 1), ('a', 1), ('t', 3), ('d', 1), (' ', 2), ('m', 1), ('o', 2), ('s', 1), ('f', 1), ('n', 1)]);
+
+        let arr = ["b", "a", "b", "c", "a", "b"];
+        let freqs = arr.iter().count_freq_first_seen().collect::<Vec<_>>();
+        assert_eq!(freqs, vec![(&"b", 3), (&"a", 2), (&"c", 1)]);
+
+        let arr = [1];
+        let freqs = arr.into_iter().count_freq_first_seen().collect::<Vec<_>>();
+        assert_eq!(freqs, vec![(1, 1)]);
+    }
+
+    #[test]
+    fn test_count_freq_into() {
+        let s = "Repeated most often";
+        let freqs = s.chars().count_freq_into::<12>().unwrap();
+        assert_eq!(freqs.as_slice(), &[('R', 1), ('e', 4), ('p', 1), ('a', 1), ('t', 3), ('d', 1), (' ', 2), ('m', 1), ('o', 2), ('s', 1), ('f', 1), ('n', 1)]);
+        assert_eq!(s.chars().count_freq_into::<11>(), Err(IterExtdError::CapacityExceeded));
+
+        let freqs = [7, 7, 7].into_iter().count_freq_into::<1>().unwrap();
+        assert_eq!(freqs.collect::<Vec<_>>(), vec![(7, 3)]);
+
+        let arr: [u8; 0] = [];
+        assert!(arr.into_iter().count_freq_into::<0>().unwrap().is_empty());
+        assert_eq!([1].into_iter().count_freq_into::<0>(), Err(IterExtdError::CapacityExceeded));
+
+        let arr = [1.5, f64::NAN, 1.5];
+        let freqs = arr.into_iter().count_freq_into::<3>().unwrap();
+        assert_eq!(freqs.len(), 2);
+        assert_eq!(freqs.as_slice()[0], (1.5, 2));
+    }
 }
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use iterextd::IterExtd;
+    use iterextd::{Extrapolation, IterExtd};
 
     #[test]
     fn test_extrapolate() {
@@ -25,4 +25,88 @@ mod tests {
         let iter = arr.into_iter().extrapolate();
         assert_eq!(format!("{:?}", iter), "Extrapolate { iter: IntoIter([2, 4, 6, 8]), arg_one: 0, arg_two: 0 }");
     }
+
+    #[test]
+    fn test_extrapolate_with() {
+        let arr = [5, 3];
+        let iter = arr.into_iter().extrapolate_with::<1>(Extrapolation::Constant);
+        assert_eq!(iter.take(4).collect::<Vec<_>>(), vec![5, 3, 3, 3]);
+
+        let arr = [2, 5, 6, 9, 13];
+        let iter = arr.into_iter().extrapolate_with::<2>(Extrapolation::Linear);
+        assert_eq!(iter.take(7).collect::<Vec<_>>(), vec![2, 5, 6, 9, 13, 17, 21]);
+        let iter = arr.into_iter().extrapolate_with::<4>(Extrapolation::Linear);
+        assert_eq!(iter.take(7).collect::<Vec<_>>(), vec![2, 5, 6, 9, 13, 17, 21]);
+
+        let arr = [0i64, 1, 8, 27];
+        let iter = arr.into_iter().extrapolate_with::<4>(Extrapolation::Polynomial);
+        assert_eq!(iter.take(7).collect::<Vec<_>>(), vec![0, 1, 8, 27, 64, 125, 216]);
+        let iter = arr.into_iter().extrapolate_with::<2>(Extrapolation::Polynomial);
+        assert_eq!(iter.take(6).collect::<Vec<_>>(), vec![0, 1, 8, 27, 46, 65]);
+
+        let arr = [7.0f64];
+        let iter = arr.into_iter().extrapolate_with::<3>(Extrapolation::Polynomial);
+        assert_eq!(iter.take(3).collect::<Vec<_>>(), vec![7.0, 7.0, 7.0]);
+
+        let arr = [3u32, 6, 12];
+        let iter = arr.into_iter().extrapolate_with::<2>(Extrapolation::Geometric);
+        assert_eq!(iter.take(5).collect::<Vec<_>>(), vec![3, 6, 12, 24, 48]);
+        let arr = [0, 4];
+        let iter = arr.into_iter().extrapolate_with::<2>(Extrapolation::Geometric);
+        assert_eq!(iter.take(4).collect::<Vec<_>>(), vec![0, 4, 4, 4]);
+
+        let arr: [i32; 0] = [];
+        let mut iter = arr.into_iter().extrapolate_with::<2>(Extrapolation::Linear);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+
+        let iter = [1, 2].into_iter().extrapolate_with::<2>(Extrapolation::Linear);
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        let iter_clone = iter.clone();
+        assert_eq!(
+            iter.take(5).collect::<Vec<_>>(),
+            iter_clone.take(5).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of points is too small for the extrapolation mode")]
+    fn test_extrapolate_with_too_few_points() {
+        let _ = [1, 2].into_iter().extrapolate_with::<1>(Extrapolation::Linear);
+    }
+
+    #[test]
+    fn test_extrapolate_n() {
+        let arr = [1, 4, 9];
+        let mut iter = arr.into_iter().extrapolate_n::<3>(Extrapolation::Polynomial, 2);
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), vec![4, 9, 16, 25]);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        let arr = [1, 4, 9];
+        let iter = arr.into_iter().extrapolate_n::<3>(Extrapolation::Polynomial, 0);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 4, 9]);
+
+        let arr: [u8; 0] = [];
+        let iter = arr.into_iter().extrapolate_n::<2>(Extrapolation::Linear, 5);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![]);
+
+        let iter = (1..10)
+            .filter(|x| x % 4 == 0)
+            .extrapolate_n::<2>(Extrapolation::Linear, 2);
+        assert_eq!(iter.size_hint(), (0, Some(11)));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![4, 8, 12, 16]);
+
+        let iter = [1u8].into_iter().extrapolate_n::<1>(Extrapolation::Constant, 1);
+        assert_eq!(
+            format!("{:?}", iter),
+            "ExtrapolateN { iter: ExtrapolateWith { iter: Fuse { iter: Some(IntoIter([1])) }, \
+             mode: Constant, history: [0], len: 0 }, count: 1 }"
+        );
+    }
 }
@@ -16,4 +16,81 @@ mod tests {
         let no_gcd = vec![2, 3, 5];
         assert_eq!(no_gcd.iter().gcd(), Some(1));
     }
+
+    #[test]
+    fn test_gcd_by_value() {
+        assert_eq!([24, 36, 48].into_iter().gcd_by_value(), Some(12));
+        assert_eq!([42_u64].into_iter().gcd_by_value(), Some(42));
+        assert_eq!([-42].into_iter().gcd_by_value(), Some(42));
+        assert_eq!([0, -9, 6].into_iter().gcd_by_value(), Some(3));
+        assert_eq!([0, 0].into_iter().gcd_by_value(), Some(0));
+        assert_eq!([2, 3, 5].into_iter().gcd_by_value(), Some(1));
+        assert_eq!([0_i8; 0].into_iter().gcd_by_value(), None);
+    }
+
+    #[test]
+    fn test_gcd_by_value_signed_min() {
+        assert_eq!([i8::MIN].into_iter().gcd_by_value(), None);
+        assert_eq!([i8::MIN, i8::MIN].into_iter().gcd_by_value(), None);
+        assert_eq!([0, i64::MIN, 0].into_iter().gcd_by_value(), None);
+        assert_eq!([i8::MIN, 96].into_iter().gcd_by_value(), Some(32));
+        assert_eq!([i8::MIN, -6].into_iter().gcd_by_value(), Some(2));
+        assert_eq!([i128::MIN + 1].into_iter().gcd_by_value(), Some(i128::MAX));
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!([4, 6, 10].into_iter().lcm(), Some(60));
+        assert_eq!([-4, -6].into_iter().lcm(), Some(12));
+        assert_eq!([7_u32].into_iter().lcm(), Some(7));
+        assert_eq!([3, 0, 5].into_iter().lcm(), Some(0));
+        assert_eq!([0, 0].into_iter().lcm(), Some(0));
+        assert_eq!([15_u8, 17].into_iter().lcm(), Some(255));
+        assert_eq!([16_u8, 17].into_iter().lcm(), None);
+        assert_eq!([i8::MIN].into_iter().lcm(), None);
+        assert_eq!([0, i8::MIN].into_iter().lcm(), Some(0));
+        assert_eq!([i16::MIN, 0].into_iter().lcm(), Some(0));
+        assert_eq!([i8::MIN, 3, 0].into_iter().lcm(), Some(0));
+        assert_eq!([2, i8::MIN].into_iter().lcm(), None);
+        assert_eq!((1..=20_u64).lcm(), Some(232_792_560));
+        assert_eq!((1..=50_u32).lcm(), None);
+        assert_eq!([0_i64; 0].into_iter().lcm(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_extended_gcd() {
+        let check = |arr: &[i64]| {
+            let (gcd, coeffs) = arr.iter().copied().extended_gcd().unwrap();
+            assert_eq!(Some(gcd), arr.iter().copied().gcd_by_value());
+            assert_eq!(coeffs.len(), arr.len());
+            assert_eq!(arr.iter().zip(&coeffs).map(|(a, c)| a * c).sum::<i64>(), gcd);
+        };
+        check(&[240, 46]);
+        check(&[6, 10, 15]);
+        check(&[-12, 18, -27, 0]);
+        check(&[0, 0]);
+        check(&[-5]);
+        check(&[1_000_000_007, 998_244_353, 123_456_789]);
+
+        assert_eq!([-5].into_iter().extended_gcd(), Some((5, vec![-1])));
+        assert_eq!([0_i32; 0].into_iter().extended_gcd(), None);
+        assert_eq!([i8::MAX, 2, 3].into_iter().extended_gcd(), Some((1, vec![1, -63, 0])));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_extended_gcd_signed_min() {
+        assert_eq!([i8::MIN].into_iter().extended_gcd(), None);
+        assert_eq!([i8::MIN, 0].into_iter().extended_gcd(), None);
+        assert_eq!([0, i8::MIN].into_iter().extended_gcd(), None);
+        assert_eq!([i8::MIN, i8::MIN].into_iter().extended_gcd(), None);
+        assert_eq!([i8::MIN, -1].into_iter().extended_gcd(), Some((1, vec![0, -1])));
+        assert_eq!([-1, i8::MIN].into_iter().extended_gcd(), Some((1, vec![-1, 0])));
+
+        let (gcd, coeffs) = [i8::MIN, 96].into_iter().extended_gcd().unwrap();
+        assert_eq!(gcd, 32);
+        assert_eq!(i32::from(i8::MIN) * i32::from(coeffs[0]) + 96 * i32::from(coeffs[1]), 32);
+        assert_eq!([i64::MIN, 6, 0].into_iter().extended_gcd().map(|(gcd, _)| gcd), Some(2));
+    }
 }
@@ -0,0 +1,85 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use iterextd::IterExtd;
+    use std::ops::Bound::{Excluded, Included};
+    use std::ops::RangeBounds;
+
+    #[test]
+    fn test_histogram() {
+        let arr = [0, 1, 2, 2, 5, 7, 8];
+        let hist = arr.into_iter().histogram(2).collect::<Vec<_>>();
+        assert_eq!(hist, vec![((Included(0), Excluded(4)), 4), ((Included(4), Included(8)), 3)]);
+        assert!(hist[1].0.contains(&8));
+
+        let arr = [-1.0, 0.5, f64::NAN, 1.0, f64::INFINITY];
+        let hist = arr.into_iter().histogram(4).collect::<Vec<_>>();
+        assert_eq!(hist, vec![
+            ((Included(-1.0), Excluded(-0.5)), 1),
+            ((Included(-0.5), Excluded(0.0)), 0),
+            ((Included(0.0), Excluded(0.5)), 0),
+            ((Included(0.5), Included(1.0)), 2),
+        ]);
+
+        let hist = [3_u8, 3, 3].into_iter().histogram(3).collect::<Vec<_>>();
+        assert_eq!(hist, vec![((Included(3), Included(3)), 3)]);
+
+        let hist = [3.0, 3.0].into_iter().histogram(2).collect::<Vec<_>>();
+        assert_eq!(hist, vec![((Included(3.0), Excluded(3.0)), 0), ((Included(3.0), Included(3.0)), 2)]);
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.into_iter().histogram(3).count(), 0);
+
+        let hist = [i64::MIN, i64::MAX].into_iter().histogram(1).collect::<Vec<_>>();
+        assert_eq!(hist, vec![((Included(i64::MIN), Included(i64::MAX)), 2)]);
+    }
+
+    #[test]
+    fn test_histogram_precision() {
+        let arr = [u64::MAX, u64::MAX - 1, u64::MAX - 3];
+        let hist = arr.into_iter().histogram(3).collect::<Vec<_>>();
+        assert_eq!(hist, vec![
+            ((Included(u64::MAX - 3), Excluded(u64::MAX - 2)), 1),
+            ((Included(u64::MAX - 2), Excluded(u64::MAX - 1)), 0),
+            ((Included(u64::MAX - 1), Included(u64::MAX)), 2),
+        ]);
+
+        let arr = [0_i8, 1, 2, 3, 4, 5, 6];
+        let hist = arr.into_iter().histogram(4).collect::<Vec<_>>();
+        for (bounds, count) in hist {
+            assert_eq!(arr.iter().filter(|value| bounds.contains(*value)).count(), count);
+        }
+    }
+
+    #[test]
+    fn test_histogram_narrow_integers() {
+        let hist = [0_u8, 1, 2].into_iter().histogram(5).collect::<Vec<_>>();
+        assert_eq!(hist, vec![
+            ((Included(0), Excluded(1)), 1),
+            ((Included(1), Excluded(2)), 1),
+            ((Included(2), Included(2)), 1),
+        ]);
+
+        let hist = [-2_i8, 2, 2].into_iter().histogram(9).collect::<Vec<_>>();
+        assert_eq!(hist.len(), 5);
+        let starts = hist.iter().map(|&((start, _), _)| start).collect::<Vec<_>>();
+        assert_eq!(starts, [-2, -1, 0, 1, 2].map(Included));
+        assert_eq!(hist.iter().map(|&(_, count)| count).collect::<Vec<_>>(), vec![1, 0, 0, 0, 2]);
+
+        let hist = [i128::MIN, i128::MAX].into_iter().histogram(4).collect::<Vec<_>>();
+        assert_eq!(hist.len(), 4);
+
+        let hist = [u128::MAX - 1, u128::MAX].into_iter().histogram(4).collect::<Vec<_>>();
+        assert_eq!(hist, vec![
+            ((Included(u128::MAX - 1), Excluded(u128::MAX)), 1),
+            ((Included(u128::MAX), Included(u128::MAX)), 1),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of bins must be non-zero")]
+    fn test_histogram_zero_bins() {
+        let _ = [1, 2].into_iter().histogram(0);
+    }
+}
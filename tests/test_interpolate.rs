@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod tests {
+    use iterextd::{IterExtd, Rounding};
+
+    #[test]
+    fn test_interpolate() {
+        let arr = [0, 4, 8];
+        let iter = arr.into_iter().interpolate(3);
+        assert_eq!(iter.len(), 9);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let arr = [9u8, 0];
+        let vec = arr.into_iter().interpolate(2).collect::<Vec<_>>();
+        assert_eq!(vec, vec![9, 6, 3, 0]);
+
+        let arr = [0i8, 5];
+        let vec = arr.into_iter().interpolate(1).collect::<Vec<_>>();
+        assert_eq!(vec, vec![0, 2, 5]);
+        let arr = [5i8, 0];
+        let vec = arr.into_iter().interpolate(1).collect::<Vec<_>>();
+        assert_eq!(vec, vec![5, 3, 0]);
+
+        let arr = [1.0f64, 2.0];
+        let vec = arr.into_iter().interpolate(4).collect::<Vec<_>>();
+        assert_eq!(vec, vec![1.0, 1.2, 1.4, 1.6, 1.8, 2.0]);
+
+        let arr = [1, 2, 3];
+        let vec = arr.into_iter().interpolate(0).collect::<Vec<_>>();
+        assert_eq!(vec, vec![1, 2, 3]);
+
+        let arr = [7];
+        let iter = arr.into_iter().interpolate(5);
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![7]);
+
+        let arr: [i32; 0] = [];
+        let iter = arr.into_iter().interpolate(5);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn test_interpolate_size_hint() {
+        let mut iter = [0, 3, 6].into_iter().interpolate(2);
+        let mut len = 7;
+        while iter.next().is_some() {
+            len -= 1;
+            assert_eq!(iter.size_hint(), (len, Some(len)));
+        }
+        assert_eq!(len, 0);
+
+        let iter = (0..10).filter(|x| x % 3 == 0).interpolate(2);
+        assert_eq!(iter.size_hint(), (0, Some(28)));
+        assert_eq!(iter.count(), 10);
+
+        let iter = (0..).interpolate(1);
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn test_interpolate_rounded() {
+        let arr = [0, 10, 0];
+        let vec = arr
+            .into_iter()
+            .interpolate_rounded(2, Rounding::Floor)
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![0, 3, 6, 10, 6, 3, 0]);
+        let vec = arr
+            .into_iter()
+            .interpolate_rounded(2, Rounding::Nearest)
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![0, 3, 7, 10, 7, 3, 0]);
+        let vec = arr
+            .into_iter()
+            .interpolate_rounded(2, Rounding::Ceil)
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![0, 4, 7, 10, 7, 4, 0]);
+
+        let arr = [-3i32, 0, -3];
+        let vec = arr
+            .into_iter()
+            .interpolate_rounded(1, Rounding::Nearest)
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![-3, -1, 0, -1, -3]);
+        let vec = arr
+            .into_iter()
+            .interpolate_rounded(1, Rounding::Floor)
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![-3, -2, 0, -2, -3]);
+
+        let arr = [0u8, 255];
+        let vec = arr
+            .into_iter()
+            .interpolate_rounded(4, Rounding::Nearest)
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![0, 51, 102, 153, 204, 255]);
+
+        let arr = [250u8, 0];
+        let vec = arr
+            .into_iter()
+            .interpolate_rounded(99, Rounding::Ceil)
+            .collect::<Vec<_>>();
+        assert_eq!(vec.len(), 101);
+        assert_eq!(vec[1], 248);
+        assert_eq!(vec[99], 3);
+    }
+
+    #[test]
+    fn test_interpolate_wide_spans() {
+        let vec = [0u8, 200].into_iter().interpolate(3).collect::<Vec<_>>();
+        assert_eq!(vec, vec![0, 50, 100, 150, 200]);
+
+        let vec = [-100i8, 100, -100].into_iter().interpolate(3).collect::<Vec<_>>();
+        assert_eq!(vec, vec![-100, -50, 0, 50, 100, 50, 0, -50, -100]);
+
+        let vec = [-100i8, 100]
+            .into_iter()
+            .interpolate_rounded(1, Rounding::Nearest)
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![-100, 0, 100]);
+
+        let vec = [100i8, -100]
+            .into_iter()
+            .interpolate_rounded(2, Rounding::Floor)
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![100, 33, -34, -100]);
+
+        let vec = [i128::MIN, i128::MAX].into_iter().interpolate(1).collect::<Vec<_>>();
+        assert_eq!(vec, vec![i128::MIN, -1, i128::MAX]);
+
+        let vec = [u128::MAX, 0]
+            .into_iter()
+            .interpolate_rounded(1, Rounding::Ceil)
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![u128::MAX, u128::MAX / 2 + 1, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of inserted values must be less than usize::MAX")]
+    fn test_interpolate_count_overflow() {
+        let _ = [0u8, 1].into_iter().interpolate(usize::MAX);
+    }
+
+    #[test]
+    fn test_interpolate_count_wider_than_type() {
+        let vec = [0u8, 255].into_iter().interpolate(254).collect::<Vec<_>>();
+        assert_eq!(vec, (0..=255).collect::<Vec<u8>>());
+
+        let vec = [0u8, 255].into_iter().interpolate(255).collect::<Vec<_>>();
+        assert_eq!(vec.len(), 257);
+        assert_eq!((vec[1], vec[255], vec[256]), (0, 254, 255));
+
+        let vec = [-1i8, 1].into_iter().interpolate_rounded(999, Rounding::Nearest).collect::<Vec<_>>();
+        assert_eq!(vec.len(), 1001);
+        assert_eq!((vec[249], vec[250], vec[749], vec[750]), (-1, 0, 0, 1));
+    }
+
+    #[test]
+    fn test_interpolate_clone() {
+        let iter = [1.5f32, -1.5].into_iter().interpolate(2);
+        let iter_clone = iter.clone();
+        assert_eq!(iter.collect::<Vec<_>>(), iter_clone.collect::<Vec<_>>());
+    }
+}
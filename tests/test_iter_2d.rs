@@ -1,3 +1,5 @@
+#[cfg(feature = "alloc")]
+use iterextd::{Flip2D, Rotation2D};
 use iterextd::Iter2D;
 
 #[test]
@@ -319,3 +321,363 @@ fn test_overlay_2d_debug() {
         overlay_row_padding: 2, in_overlay_row: false }";  
     assert_eq!(format!("{:?}", iter), debug_str);
 }
+
+#[test]
+fn test_crop_2d() {
+    let res_vec = (0..20).crop_2d((5, 4), (3, 2), (1, 1)).collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![6, 7, 8, 11, 12, 13]);
+
+    let res_vec = (0..20).crop_2d((5, 4), (5, 4), (0, 0)).collect::<Vec<_>>();
+    assert_eq!(res_vec, (0..20).collect::<Vec<_>>());
+
+    let res_vec = (0..20).crop_2d((5, 4), (1, 4), (4, 0)).collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![4, 9, 14, 19]);
+
+    let res_vec = (0..20).crop_2d((5, 4), (2, 2), (3, 2)).collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![13, 14, 18, 19]);
+
+    // The base iterator ends inside the cropped region.
+    let res_vec = (0..12).crop_2d((5, 4), (3, 2), (1, 1)).collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![6, 7, 8, 11]);
+}
+
+#[test]
+fn test_crop_2d_len_size_hint() {
+    let mut iter = (0..20).crop_2d((5, 4), (3, 2), (1, 1));
+    assert_eq!(iter.len(), 6);
+    iter.next();
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    iter.nth(2);
+    assert_eq!(iter.len(), 2);
+
+    for len in 0..=20 {
+        let iter = (0..len).crop_2d((5, 4), (3, 2), (1, 1));
+        assert_eq!(iter.len(), iter.count());
+    }
+
+    let iter = (0..).crop_2d((5, 4), (3, 2), (1, 1));
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+}
+
+#[test]
+#[should_panic(expected = "the offset and size of the cropped abstract 2D data exceed the bounds")]
+fn test_crop_2d_panic_offset() {
+    let _ = (0..20).crop_2d((5, 4), (3, 2), (3, 1));
+}
+
+#[test]
+#[should_panic(expected = "the size of abstract 2D data must not be zero")]
+fn test_crop_2d_panic_size_zero() {
+    let _ = (0..20).crop_2d((5, 4), (0, 2), (1, 1));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_transpose_2d() {
+    let res_vec = (0..12).transpose_2d((4, 3)).collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7, 11]);
+
+    let res_vec = res_vec.into_iter().transpose_2d((3, 4)).collect::<Vec<_>>();
+    assert_eq!(res_vec, (0..12).collect::<Vec<_>>());
+
+    let vec = ['a', 'b', 'c'];
+    let res_vec = vec.iter().transpose_2d((3, 1)).collect::<String>();
+    assert_eq!(res_vec, "abc");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_rotate_2d() {
+    let vec = (0..6).collect::<Vec<_>>();
+
+    let res_vec = vec.iter().rotate_2d((3, 2), Rotation2D::Deg90).copied().collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![3, 0, 4, 1, 5, 2]);
+
+    let res_vec = vec.iter().rotate_2d((3, 2), Rotation2D::Deg180).copied().collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![5, 4, 3, 2, 1, 0]);
+
+    let res_vec = vec.iter().rotate_2d((3, 2), Rotation2D::Deg270).copied().collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![2, 5, 1, 4, 0, 3]);
+
+    let res_vec = vec
+        .iter()
+        .rotate_2d((3, 2), Rotation2D::Deg90)
+        .rotate_2d((2, 3), Rotation2D::Deg270)
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_flip_2d() {
+    let res_vec = (0..6).flip_2d((2, 3), Flip2D::Horizontal).collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![1, 0, 3, 2, 5, 4]);
+
+    let res_vec = (0..6).flip_2d((2, 3), Flip2D::Vertical).collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![4, 5, 2, 3, 0, 1]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_tile_2d() {
+    let res_vec = (0..4).tile_2d((2, 2), (2, 2)).collect::<Vec<_>>();
+    assert_eq!(
+        res_vec,
+        vec![
+            0, 1, 0, 1,
+            2, 3, 2, 3,
+            0, 1, 0, 1,
+            2, 3, 2, 3,
+        ]
+    );
+
+    let res_vec = (0..4).tile_2d((2, 2), (1, 1)).collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![0, 1, 2, 3]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_remap_2d_size_hint() {
+    let mut iter = (0..6).rotate_2d((3, 2), Rotation2D::Deg90);
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+
+    let iter = (0..4).tile_2d((2, 2), (3, 2));
+    assert_eq!(iter.size_hint(), (24, Some(24)));
+
+    // The base iterator is shorter than the declared size.
+    let iter = (0..5).transpose_2d((3, 2));
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 3, 1, 4, 2]);
+
+    let mut iter = (0..5).rotate_2d((3, 2), Rotation2D::Deg90);
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.by_ref().collect::<Vec<_>>(), vec![3, 0, 4, 1]);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let iter = (0..6).filter(|x| x % 2 == 0).flip_2d((3, 2), Flip2D::Vertical);
+    assert_eq!(iter.size_hint(), (0, Some(6)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_remap_2d_len() {
+    let mut iter = (0..6).transpose_2d((3, 2));
+    assert_eq!(iter.len(), 6);
+    iter.next();
+    assert_eq!(iter.len(), 5);
+
+    let iter = (0..6).flip_2d((3, 2), Flip2D::Horizontal);
+    assert_eq!(iter.len(), 6);
+
+    let iter = (0..4).tile_2d((2, 2), (2, 3));
+    assert_eq!(iter.len(), 24);
+
+    let mut iter = (0..4).rotate_2d((3, 2), Rotation2D::Deg270);
+    let len = iter.len();
+    assert_eq!(len, iter.by_ref().count());
+    assert_eq!(iter.len(), 0);
+
+    let mut iter = (0..5).rotate_2d((3, 2), Rotation2D::Deg180);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_remap_2d_owned_source() {
+    let vec = (0..12).map(|x| x.to_string()).collect::<Vec<_>>();
+    let res_vec = vec.clone().into_iter().rotate_2d((4, 3), Rotation2D::Deg180).collect::<Vec<_>>();
+    assert_eq!(res_vec, vec.into_iter().rev().collect::<Vec<_>>());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "the size of abstract 2D data overflows usize")]
+fn test_tile_2d_panic_overflow() {
+    let _ = (0..6).tile_2d((3, 2), (usize::MAX, 1));
+}
+
+#[test]
+#[should_panic(expected = "the offset and size of the cropped abstract 2D data exceed the bounds")]
+fn test_crop_2d_panic_offset_overflow() {
+    let _ = (0..16).crop_2d((4, 4), (2, 2), (usize::MAX, 0));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "the size of abstract 2D data must not be zero")]
+fn test_transpose_2d_panic_size_zero() {
+    let _ = (0..6).transpose_2d((0, 6));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "the size of abstract 2D data must not be zero")]
+fn test_tile_2d_panic_tiles_zero() {
+    let _ = (0..6).tile_2d((3, 2), (2, 0));
+}
+
+#[test]
+fn test_overlay_2d_with() {
+    let res_vec = [1u16; 16]
+        .into_iter()
+        .overlay_2d_with(10..14u16, (4, 4), (2, 2), (1, 2), |base, over| base + over)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        res_vec,
+        vec![
+            1, 1, 1, 1,
+            1, 1, 1, 1,
+            1, 11, 12, 1,
+            1, 13, 14, 1,
+        ]
+    );
+
+    // Overlay elements may have a different type.
+    let res_vec = (0..9)
+        .overlay_2d_with([true, false, false, true].into_iter(), (3, 3), (2, 2), (0, 0), |base, keep| {
+            if keep { base } else { -1 }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![0, -1, 2, -1, 4, 5, 6, 7, 8]);
+
+    // The base elements stay unchanged when the overlay iterator is shorter.
+    let res_vec = [0; 9]
+        .into_iter()
+        .overlay_2d_with([5].into_iter(), (3, 3), (3, 3), (0, 0), |_, over| over)
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![5, 0, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_overlay_2d_with_matches_overlay_2d() {
+    for offset in [(0, 0), (1, 1), (2, 0), (0, 2), (2, 2)] {
+        for size in [(1, 1), (2, 2), (3, 2), (4, 4)] {
+            if offset.0 + size.0 > 4 || offset.1 + size.1 > 4 {
+                continue;
+            }
+            let overlay = (100..).take(size.0 * size.1);
+            let expected = (0..16).overlay_2d(overlay.clone(), (4, 4), size, offset);
+            let blended = (0..16).overlay_2d_with(overlay, (4, 4), size, offset, |_, over| over);
+            assert_eq!(blended.collect::<Vec<_>>(), expected.collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn test_overlay_2d_transparent() {
+    let sprite = [Some(1), None, None, Some(4)];
+    let res_vec = [0; 9]
+        .into_iter()
+        .overlay_2d_transparent(sprite.into_iter(), (3, 3), (2, 2), (1, 1))
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![0, 0, 0, 0, 1, 0, 0, 0, 4]);
+}
+
+#[test]
+fn test_overlay_2d_with_len_size_hint() {
+    let iter = (1..101).take(90).overlay_2d_with(1..65, (10, 10), (8, 8), (1, 1), |a, b| a + b);
+    assert_eq!(iter.size_hint(), (90, Some(90)));
+    assert_eq!(iter.len(), 90);
+}
+
+#[test]
+#[should_panic(expected = "the offset and size of the inserted abstract 2D data exceed the bounds")]
+fn test_overlay_2d_with_panic_offset() {
+    let _ = (1..100).overlay_2d_with(1..=40, (10, 10), (4, 10), (7, 0), |a, b| a + b);
+}
+
+#[test]
+fn test_overlay_2d_clipped() {
+    let overlay = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+    // Inside the bounds it matches overlay_2d.
+    let res_vec = [0; 16]
+        .into_iter()
+        .overlay_2d_clipped(overlay.into_iter(), (4, 4), (3, 3), (1, 1))
+        .collect::<Vec<_>>();
+    let expected = [0; 16]
+        .into_iter()
+        .overlay_2d(overlay.into_iter(), (4, 4), (3, 3), (1, 1))
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, expected);
+
+    // Top left corner is clipped.
+    let res_vec = [0; 16]
+        .into_iter()
+        .overlay_2d_clipped(overlay.into_iter(), (4, 4), (3, 3), (-1, -2))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        res_vec,
+        vec![
+            8, 9, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]
+    );
+
+    // Bottom right corner is clipped.
+    let res_vec = [0; 16]
+        .into_iter()
+        .overlay_2d_clipped(overlay.into_iter(), (4, 4), (3, 3), (2, 3))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        res_vec,
+        vec![
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 1, 2,
+        ]
+    );
+
+    // Overlay wider than the target area.
+    let res_vec = [0; 6]
+        .into_iter()
+        .overlay_2d_clipped(overlay.into_iter(), (2, 3), (3, 3), (-1, 0))
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![2, 3, 5, 6, 8, 9]);
+
+    // Entirely outside the target area.
+    let res_vec = [0; 9]
+        .into_iter()
+        .overlay_2d_clipped(overlay.into_iter(), (3, 3), (3, 3), (-3, 0))
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![0; 9]);
+
+    let res_vec = [0; 9]
+        .into_iter()
+        .overlay_2d_clipped(overlay.into_iter(), (3, 3), (3, 3), (isize::MIN, isize::MAX))
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![0; 9]);
+}
+
+#[test]
+fn test_overlay_2d_clipped_short_iters() {
+    // The overlay iterator is shorter than the overlay size.
+    let res_vec = [0; 9]
+        .into_iter()
+        .overlay_2d_clipped([1, 2, 3].into_iter(), (3, 3), (2, 2), (-1, 0))
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![2, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    // The base iterator is longer than the target area.
+    let res_vec = [0; 12]
+        .into_iter()
+        .overlay_2d_clipped([1, 2, 3, 4].into_iter(), (3, 3), (2, 2), (1, 2))
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0]);
+
+    let iter = (0..20).overlay_2d_clipped(0..4, (5, 4), (2, 2), (-1, -1));
+    assert_eq!(iter.len(), 20);
+}
+
+#[test]
+#[should_panic(expected = "the size of abstract 2D data must not be zero")]
+fn test_overlay_2d_clipped_panic_size_zero() {
+    let _ = (0..9).overlay_2d_clipped(0..4, (3, 3), (2, 0), (0, 0));
+}
@@ -1,6 +1,9 @@
+#![cfg(feature = "alloc")]
+
 #[cfg(test)]
 mod tests {
     use iterextd::IterExtd;
+    use std::ops::{Bound, RangeInclusive};
     use itertools::Itertools;
 
     #[test]
@@ -23,7 +26,7 @@ mod tests {
         assert_eq!(vec.iter().missing_integers().collect::<Vec<_>>(), vec![a - 2]);
 
         let vec = vec![a, b, c, d];
-        assert_eq!(vec.iter().missing_integers().collect::<Vec<_>>(), vec![]);
+        assert_eq!(vec.iter().missing_integers().collect::<Vec<_>>(), vec![a - 2]);
 
         let vec = vec![4, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6, 7, 7, 8];
         assert_eq!(vec.iter().missing_integers().collect::<Vec<_>>(), vec![]);
@@ -49,7 +52,7 @@ mod tests {
         assert_eq!(vec.iter().missing_integers().collect::<Vec<_>>(), vec![a - 2]);
 
         let vec = vec![a, b, c, d];
-        assert_eq!(vec.iter().missing_integers().collect::<Vec<_>>(), vec![]);
+        assert_eq!(vec.iter().missing_integers().collect::<Vec<_>>(), vec![a - 2]);
 
         let vec = vec![8, 8, 8, 8, 8];
         assert_eq!(vec.iter().missing_integers().collect::<Vec<_>>(), vec![]);
@@ -105,4 +108,136 @@ mod tests {
         let iter = vec.iter().unique();
         assert_eq!(iter.missing_integers_uqsort().collect::<Vec<_>>(), vec![]);
     }
+
+    #[test]
+    fn test_missing_int_wide_range() {
+        let arr = [-5i32, 5, -3];
+        assert_eq!(arr.iter().missing_integers().collect::<Vec<_>>(), vec![-4, -2, -1, 0, 1, 2, 3, 4]);
+
+        let arr = [i8::MAX, i8::MIN + 1, i8::MIN + 3];
+        let iter = arr.iter().missing_integers();
+        let vec = iter.collect::<Vec<_>>();
+        assert_eq!(vec.len(), 252);
+        assert_eq!(vec[..2], [i8::MIN + 2, i8::MIN + 4]);
+        assert_eq!(vec[251], i8::MAX - 1);
+
+        let arr = [u128::MAX, u128::MAX - 3, 0, 2];
+        let mut iter = arr.iter().missing_integers();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        assert_eq!(iter.next(), Some(4));
+
+        let arr = [i128::MIN, i128::MAX, 0];
+        let mut iter = arr.iter().missing_integers();
+        assert_eq!(iter.next(), Some(i128::MIN + 1));
+        assert_eq!(iter.next(), Some(i128::MIN + 2));
+    }
+
+    #[test]
+    fn test_missing_ranges() {
+        let vec = vec![9i8, -8, -8, -5, -4, -3, -2, 0, 1, 2, 4, 5, 6, 8, 10];
+        let ranges = vec.iter().missing_ranges().collect::<Vec<_>>();
+        assert_eq!(ranges, vec![-7..=-6, -1..=-1, 3..=3, 7..=7]);
+
+        let arr = [i64::MIN, i64::MAX, 0];
+        let ranges = arr.iter().missing_ranges().collect::<Vec<_>>();
+        assert_eq!(ranges, vec![i64::MIN + 1..=-1, 1..=i64::MAX - 1]);
+
+        let arr = [u128::MAX, 0];
+        let ranges = arr.iter().missing_ranges().collect::<Vec<RangeInclusive<_>>>();
+        assert_eq!(ranges, vec![1..=u128::MAX - 1]);
+
+        let arr = [i128::MIN, i128::MAX];
+        let ranges = arr.iter().missing_ranges().collect::<Vec<_>>();
+        assert_eq!(ranges, vec![i128::MIN + 1..=i128::MAX - 1]);
+
+        let arr = [3u16, 4, 5, 4];
+        assert_eq!(arr.iter().missing_ranges().collect::<Vec<_>>(), vec![]);
+
+        let arr = [0u8; 0];
+        assert_eq!(arr.iter().missing_ranges().collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn test_missing_ranges_uqsort() {
+        let vec = vec![-8i8, -5, -4, -3, -2, 0, 1, 2, 4, 5, 6, 8, 9, 10];
+        let ranges = vec.iter().missing_ranges_uqsort().collect::<Vec<_>>();
+        assert_eq!(ranges, vec![-7..=-6, -1..=-1, 3..=3, 7..=7]);
+
+        let arr = [i32::MIN, i32::MAX];
+        let ranges = arr.iter().missing_ranges_uqsort().collect::<Vec<_>>();
+        assert_eq!(ranges, vec![i32::MIN + 1..=i32::MAX - 1]);
+
+        let arr = [0u128, u128::MAX];
+        let mut iter = arr.iter().missing_integers_uqsort();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+
+        let arr = [7u64];
+        assert_eq!(arr.iter().missing_ranges_uqsort().collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn test_missing_int_in() {
+        let arr = [3u8, 9, 5, 6, 4, 8, 8, 4, 3];
+        assert_eq!(arr.iter().missing_integers_in(0..=10).collect::<Vec<_>>(), vec![0, 1, 2, 7, 10]);
+        assert_eq!(arr.iter().missing_integers_in(0..3).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(arr.iter().missing_integers_in(3..=6).collect::<Vec<_>>(), vec![]);
+        assert_eq!(arr.iter().missing_integers_in(7..7).collect::<Vec<_>>(), vec![]);
+        assert_eq!(arr.iter().missing_integers_in(20..=22).collect::<Vec<_>>(), vec![20, 21, 22]);
+        assert_eq!(arr.iter().missing_integers_in(250..).collect::<Vec<_>>(), vec![250, 251, 252, 253, 254, 255]);
+
+        let arr = [0u8; 0];
+        assert_eq!(arr.iter().missing_integers_in(..=2).collect::<Vec<_>>(), vec![0, 1, 2]);
+
+        let arr = [-3i16, 3, 0];
+        let vec = arr.iter().missing_integers_in(-4..4).collect::<Vec<_>>();
+        assert_eq!(vec, vec![-4, -2, -1, 1, 2]);
+
+        let arr = [i8::MIN, i8::MAX];
+        assert_eq!(arr.iter().missing_integers_in(..).count(), 254);
+    }
+
+    #[test]
+    fn test_missing_ranges_in() {
+        let arr = [3u64, 9, 5, 6, 4, 8, 8, 4, 3];
+        let ranges = arr.iter().missing_ranges_in(..).collect::<Vec<_>>();
+        assert_eq!(ranges, vec![0..=2, 7..=7, 10..=u64::MAX]);
+        let ranges = arr.iter().missing_ranges_in(5..=5).collect::<Vec<_>>();
+        assert_eq!(ranges, vec![]);
+
+        let arr = [u128::MAX, 0];
+        let ranges = arr.iter().missing_ranges_in(..).collect::<Vec<_>>();
+        assert_eq!(ranges, vec![1..=u128::MAX - 1]);
+
+        let arr = [i128::MIN, 5];
+        let ranges = arr.iter().missing_ranges_in(..).collect::<Vec<_>>();
+        assert_eq!(ranges, vec![i128::MIN + 1..=4, 6..=i128::MAX]);
+
+        let arr = [10i32, 20];
+        let ranges = arr.iter().missing_ranges_in((Bound::Excluded(10), Bound::Excluded(20)));
+        assert_eq!(ranges.collect::<Vec<_>>(), vec![11..=19]);
+        let ranges = arr.iter().missing_ranges_in((Bound::Excluded(i32::MAX), Bound::Unbounded));
+        assert_eq!(ranges.collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn test_unexpected_int_in() {
+        let arr = [3i32, 12, 5, -6, 4, 8, 12, 4, 3];
+        assert_eq!(arr.iter().unexpected_integers_in(0..10).collect::<Vec<_>>(), vec![-6, 12]);
+        assert_eq!(arr.iter().unexpected_integers_in(..).collect::<Vec<_>>(), vec![]);
+        assert_eq!(arr.iter().unexpected_integers_in(4..=4).collect::<Vec<_>>(), vec![-6, 3, 5, 8, 12]);
+
+        let arr = [u128::MAX, 0, 1];
+        assert_eq!(arr.iter().unexpected_integers_in(1..).collect::<Vec<_>>(), vec![0]);
+
+        let arr = [7u8, 1, 9, 3, 5];
+        assert_eq!(arr.iter().unexpected_integers_in(3..6).rev().collect::<Vec<_>>(), vec![9, 7, 1]);
+        let empty = (Bound::Excluded(4), Bound::Excluded(5));
+        assert_eq!(arr.iter().unexpected_integers_in(empty).collect::<Vec<_>>(), vec![1, 3, 5, 7, 9]);
+        let iter = arr.iter().unexpected_integers_in(0..=2);
+        assert_eq!(iter.size_hint().0, 0);
+        assert_eq!(iter.count(), 4);
+    }
 }
@@ -1,7 +1,10 @@
+#![cfg(feature = "alloc")]
+
 #[cfg(test)]
 mod tests {
     use iterextd::IterExtd;
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_modes() {
         let vec = vec![1, 2, 2, 3, 3, 3];
@@ -28,4 +31,46 @@ mod tests {
         let mode = arr.into_iter().modes();
         assert_eq!(format!("Mode: {:?}", mode), "Mode: Filter { iter: [(1, 1)] }");
     }
+
+    #[test]
+    fn test_modes_sorted() {
+        let arr = [3, 1, 2, 2, 3, 1, 3, 1];
+        let modes = arr.into_iter().modes_sorted().collect::<Vec<_>>();
+        assert_eq!(modes, vec![(1, 3), (3, 3)]);
+
+        let data = "10, 20, 20, 30, 30, 30";
+        let modes = data.chars().modes_sorted().collect::<Vec<_>>();
+        assert_eq!(modes, vec![('0', 6)]);
+
+        let arr = ["c", "b", "a"];
+        let modes = arr.iter().modes_sorted().collect::<Vec<_>>();
+        assert_eq!(modes, vec![(&"a", 1), (&"b", 1), (&"c", 1)]);
+
+        let arr: [char; 0] = [];
+        assert_eq!(arr.into_iter().modes_sorted().collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn test_least_common() {
+        let s = "Repeated most often";
+        let least = s.chars().least_common().collect::<Vec<_>>();
+        assert_eq!(least, vec![('R', 1), ('a', 1), ('d', 1), ('f', 1), ('m', 1), ('n', 1), ('p', 1), ('s', 1)]);
+
+        let arr = [2, 2, 1, 1, 2, 1];
+        assert_eq!(arr.into_iter().least_common().collect::<Vec<_>>(), vec![(1, 3), (2, 3)]);
+
+        let arr: [char; 0] = [];
+        assert_eq!(arr.into_iter().least_common().collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn test_top_k() {
+        let s = "Repeated most often";
+        let top = s.chars().top_k(4).collect::<Vec<_>>();
+        assert_eq!(top, vec![('e', 4), ('t', 3), (' ', 2), ('o', 2)]);
+
+        let arr = [5, 3, 5];
+        assert_eq!(arr.into_iter().top_k(10).collect::<Vec<_>>(), vec![(5, 2), (3, 1)]);
+        assert_eq!(arr.into_iter().top_k(0).collect::<Vec<_>>(), vec![]);
+    }
 }
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use iterextd::IterExtd;
+
+    #[test]
+    fn test_runs() {
+        let arr = [1, 1, 2, 3, 3, 3, 1];
+        let vec = arr.into_iter().runs().collect::<Vec<_>>();
+        assert_eq!(vec, vec![(1, 2), (2, 1), (3, 3), (1, 1)]);
+
+        let mut iter = arr.iter().runs();
+        assert_eq!(iter.size_hint(), (1, Some(7)));
+        assert_eq!(iter.next(), Some((&1, 2)));
+        assert_eq!(iter.size_hint(), (1, Some(5)));
+
+        let arr: [u8; 0] = [];
+        let mut iter = arr.into_iter().runs();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+
+        let vec = [0.5f32; 4].into_iter().runs().collect::<Vec<_>>();
+        assert_eq!(vec, vec![(0.5, 4)]);
+    }
+
+    #[test]
+    fn test_expand_runs() {
+        let arr = [(1, 2), (2, 1), (3, 0), (3, 3)];
+        let vec = arr.into_iter().expand_runs().collect::<Vec<_>>();
+        assert_eq!(vec, vec![1, 1, 2, 3, 3, 3]);
+
+        let arr: [(u8, usize); 0] = [];
+        assert_eq!(arr.into_iter().expand_runs().collect::<Vec<_>>(), vec![]);
+
+        let bitmap = [true, true, false, false, false, true, false];
+        let runs = bitmap.into_iter().runs().collect::<Vec<_>>();
+        assert_eq!(runs, vec![(true, 2), (false, 3), (true, 1), (false, 1)]);
+        let vec = runs.into_iter().expand_runs().collect::<Vec<_>>();
+        assert_eq!(vec, bitmap);
+
+        let strings = ["a", "a", "b"].map(String::from);
+        let vec = strings.clone().into_iter().runs().expand_runs().collect::<Vec<_>>();
+        assert_eq!(vec, strings);
+    }
+
+    #[test]
+    fn test_consecutive_ranges() {
+        let arr = [1u8, 2, 3, 5, 7, 8, 8, 9, 255];
+        let vec = arr.into_iter().consecutive_ranges().collect::<Vec<_>>();
+        assert_eq!(vec, vec![1..=3, 5..=5, 7..=9, 255..=255]);
+
+        let arr = [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX];
+        let vec = arr.into_iter().consecutive_ranges().collect::<Vec<_>>();
+        assert_eq!(vec, vec![i64::MIN..=i64::MIN + 1, -1..=1, i64::MAX..=i64::MAX]);
+
+        let arr = [254u8, 255, 0, 1];
+        let vec = arr.into_iter().consecutive_ranges().collect::<Vec<_>>();
+        assert_eq!(vec, vec![254..=255, 0..=1]);
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.into_iter().consecutive_ranges().collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn test_expand_ranges() {
+        let arr = [1u8..=3, 5..=5, 7..=9, 250..=255];
+        let vec = arr.clone().into_iter().expand_ranges().collect::<Vec<_>>();
+        assert_eq!(vec, vec![1, 2, 3, 5, 7, 8, 9, 250, 251, 252, 253, 254, 255]);
+        let ranges = vec.into_iter().consecutive_ranges().collect::<Vec<_>>();
+        assert_eq!(ranges, arr);
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let arr = [3..=1, 4..=4];
+        assert_eq!(arr.into_iter().expand_ranges().collect::<Vec<_>>(), vec![4]);
+
+        let arr = [i8::MIN..=i8::MAX];
+        assert_eq!(arr.into_iter().expand_ranges().count(), 256);
+
+        let mut iter = [0u32..=9].into_iter().expand_ranges();
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.size_hint(), (9, None));
+    }
+
+    #[test]
+    fn test_ranges_round_trip_with_tuples() {
+        let arr = [0u16, 1, 2, 6, 7, 9];
+        let tuples = arr.into_iter().consecutive_ranges().to_tuple_icv().collect::<Vec<_>>();
+        assert_eq!(tuples, vec![(0, 2), (6, 7), (9, 9)]);
+        let vec = tuples.into_iter().to_range_icv().expand_ranges().collect::<Vec<_>>();
+        assert_eq!(vec, arr);
+    }
+}
@@ -0,0 +1,123 @@
+use iterextd::{IterExtdError, ScaleMap, Scaler};
+
+#[test]
+fn test_scale_map_single_values() {
+    let map = ScaleMap::<u32, u8>::new(..=4095u16, ..=255u8);
+    let values = [0u16, 1, 2047, 2048, 4094, 4095].map(|x| map.scale(x));
+    assert_eq!(values, [0, 0, 127, 127, 254, 255]);
+
+    let map = ScaleMap::<u64, u16>::new(-50..=50i32, 1000..=2000u16);
+    assert_eq!([-50, 0, 50].map(|x| map.scale(x)), [1000, 1500, 2000]);
+}
+
+#[test]
+fn test_scale_map_matches_adapters() {
+    let iter = (0..=1000u16).scaling::<u32>(..=255u8);
+    let map = iter.scale_map();
+    assert_eq!(iter.collect::<Vec<_>>(), (0..=1000u16).map(|x| map.scale(x)).collect::<Vec<u8>>());
+
+    let iter = (0..=1000u16).scaling_from::<u32>(..=1000u16, -100..=100i8);
+    let map = iter.scale_map();
+    assert_eq!(map, ScaleMap::new(..=1000u16, -100..=100i8));
+    assert_eq!(iter.collect::<Vec<_>>(), (0..=1000u16).map(|x| map.scale(x)).collect::<Vec<i8>>());
+}
+
+#[test]
+fn test_scale_map_scaling_with() {
+    let map = (0..=4u8).scaling::<u16>(..=100u8).scale_map();
+    let iter = [4u8, 0, 2].into_iter().scaling_with(map);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![100, 0, 50]);
+
+    let map = ScaleMap::<i32, i8>::new(-1000..=1000i16, ..);
+    let iter = (-1000..=1000i16).step_by(500).scaling_with(map);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![-128, -65, -1, 63, 127]);
+}
+
+#[test]
+fn test_scale_map_inverse() {
+    let map = ScaleMap::<u16, i8>::new(0..=200u8, -100..=100i8);
+    let inverse = map.inverse::<u8>();
+    for x in 0..=200u8 {
+        assert_eq!(inverse.scale(map.scale(x)), x);
+    }
+    assert_eq!(inverse.inverse::<i8>(), map);
+
+    let map = ScaleMap::<u32, u8>::new(..=4095u16, ..=255u8);
+    let inverse = map.inverse::<u16>();
+    assert_eq!([0u8, 127, 255].map(|x| inverse.scale(x)), [0, 2039, 4095]);
+}
+
+#[test]
+fn test_scale_map_degenerate() {
+    let map = ScaleMap::<u16, u8>::new((3u8, 3), 10..=20u8);
+    assert_eq!(map.scale(3u8), 10);
+
+    let map = ScaleMap::<u16, u8>::new(0..=10u8, (7u8, 7));
+    assert_eq!(map.scale(5u8), 7);
+    assert_eq!(map.inverse::<u8>().scale(7u8), 0);
+
+    let map = ScaleMap::<u16, u8>::new((5u8, 5), 0..=100u8);
+    assert_eq!([4u8, 5, 6].map(|x| map.scale(x)), [0, 0, 100]);
+    let inverse = map.inverse::<u8>();
+    assert_eq!([0u8, 50, 100].map(|x| inverse.scale(x)), [5, 5, 5]);
+}
+
+#[test]
+fn test_scale_map_out_of_range() {
+    let map = ScaleMap::<u16, u8>::new(100..=200u8, 0..=100u8);
+    assert_eq!([50u8, 100, 150, 200, 255].map(|x| map.scale(x)), [0, 0, 50, 100, 100]);
+    assert_eq!(map.checked_scale(150u8), Ok(50));
+    assert_eq!(map.checked_scale(50u8), Err(IterExtdError::OutOfBounds));
+    assert_eq!(map.checked_scale(201u8), Err(IterExtdError::OutOfBounds));
+
+    let iter = [0u8, 150, 255].into_iter().scaling_with(map);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 50, 100]);
+}
+
+#[test]
+fn test_scale_map_errors() {
+    assert_eq!(ScaleMap::<u16, u8>::try_new((5, 1u8), ..), Err(IterExtdError::InvalidRange));
+    assert_eq!(ScaleMap::<u16, u8>::try_new(..=9u8, (9, 1u8)), Err(IterExtdError::InvalidRange));
+    assert_eq!(ScaleMap::<u16, u8>::try_new(..=u16::MAX, ..), Err(IterExtdError::Overflow));
+    assert_eq!(ScaleMap::<i32, i8>::try_new((10i8, 5), 0..=10i8), Err(IterExtdError::InvalidRange));
+    assert_eq!(ScaleMap::<i32, i8>::try_new(0..=10i8, (5i8, -5)), Err(IterExtdError::InvalidRange));
+    assert_eq!(ScaleMap::<u8, u8>::try_new(-1..=1i8, ..), Err(IterExtdError::Overflow));
+}
+
+#[test]
+#[should_panic(expected = "lower range bound must be less than upper")]
+fn test_scale_map_invalid_range() {
+    let _ = ScaleMap::<u16, u8>::new((5, 1u8), ..);
+}
+
+#[test]
+#[should_panic(expected = "overflow of the selected intermediate type")]
+fn test_scale_map_overflow() {
+    let _ = ScaleMap::<u16, u8>::new(..=u16::MAX, ..);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_scale_map_serde() {
+    let map = ScaleMap::<u32, u8>::new(..=4095u16, ..=255u8);
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!(json, r#"{"input_start":0,"input_end":4095,"output_start":0,"output_end":255}"#);
+    let restored: ScaleMap<u32, u8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, map);
+    assert_eq!(restored.scale(2048u16), 127);
+
+    let json = r#"{"input_start":5,"input_end":5,"output_start":0,"output_end":100}"#;
+    let restored: ScaleMap<u16, u8> = serde_json::from_str(json).unwrap();
+    assert_eq!(restored.scale(5u8), 0);
+
+    let json = r#"{"input_start":5,"input_end":1,"output_start":0,"output_end":255}"#;
+    assert!(serde_json::from_str::<ScaleMap<u32, u8>>(json).is_err());
+    let json = r#"{"input_start":0,"input_end":65535,"output_start":0,"output_end":65535}"#;
+    assert!(serde_json::from_str::<ScaleMap<u16, u16>>(json).is_err());
+
+    let json = r#"{"input_start":10,"input_end":5,"output_start":0,"output_end":10}"#;
+    assert!(serde_json::from_str::<ScaleMap<i32, i8>>(json).is_err());
+    let json = r#"{"input_start":-5,"input_end":5,"output_start":0,"output_end":10}"#;
+    let restored: ScaleMap<i32, i8> = serde_json::from_str(json).unwrap();
+    assert_eq!(restored.scale(0i32), 5);
+}
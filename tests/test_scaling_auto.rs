@@ -0,0 +1,93 @@
+use iterextd::{IterExtdError, Scaler};
+use paste::paste;
+
+macro_rules! test_scaling_auto_eq_scaling {
+        ( $input_type:ty; $($output_type:ty),* ) => {
+            $(
+                paste! {
+                    #[test]
+                    fn [<test_scaling_auto_ $input_type _ $output_type _eq_scaling>]() {
+                        let arr = [<$input_type>::MIN, <$input_type>::MIN / 2 + 3, 0 as $input_type, 7, <$input_type>::MAX / 3, <$input_type>::MAX];
+                        let range = <$output_type>::MIN + 1..=<$output_type>::MAX / 5;
+                        let expected = arr.into_iter().scaling::<u128>(range.clone()).collect::<Vec<_>>();
+                        let scaled = arr.into_iter().scaling_auto(range.clone()).collect::<Vec<_>>();
+                        assert_eq!(scaled, expected);
+
+                        let expected = arr[1..3].iter().copied().scaling::<u128>(..).collect::<Vec<$output_type>>();
+                        let scaled = arr[1..3].iter().copied().scaling_auto(..).collect::<Vec<$output_type>>();
+                        assert_eq!(scaled, expected);
+                    }
+                }
+            )*
+        }
+    }
+
+test_scaling_auto_eq_scaling!(u8; u8, i8, u16, i32, u64);
+test_scaling_auto_eq_scaling!(i8; u8, i8, i16, u32, i64);
+test_scaling_auto_eq_scaling!(u16; u8, i16, u32, usize);
+test_scaling_auto_eq_scaling!(i32; i8, u16, i32, isize);
+test_scaling_auto_eq_scaling!(u32; u8, i32);
+
+#[test]
+fn test_scaling_auto_u128() {
+    let arr = [0, u128::MAX / 3, u128::MAX];
+    let iter = arr.into_iter().scaling_auto(..);
+    assert_eq!(iter.collect::<Vec<u128>>(), arr.to_vec());
+
+    let iter = arr.into_iter().scaling_auto(..);
+    assert_eq!(iter.collect::<Vec<u64>>(), vec![0, u64::MAX / 3, u64::MAX]);
+
+    let iter = arr.into_iter().scaling_auto(..);
+    assert_eq!(iter.collect::<Vec<i128>>(), vec![i128::MIN, i128::MIN + (u128::MAX / 3) as i128, i128::MAX]);
+
+    let iter = [u64::MAX as u128, u128::MAX - 1, u128::MAX].into_iter().scaling_auto(10..=20u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 19, 20]);
+
+    let arr = [i64::MIN, -1, 0, i64::MAX];
+    let iter = arr.into_iter().scaling_auto(..);
+    assert_eq!(iter.collect::<Vec<i128>>(), vec![i128::MIN, -(1 << 63) - 1, 1 << 63, i128::MAX]);
+}
+
+#[test]
+fn test_scaling_auto_no_overflow() {
+    let iter = [0u8, 255].into_iter().scaling_auto(..=255u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 255]);
+
+    let res = [0u8, 255].into_iter().try_scaling::<u8>(..=255u8);
+    assert_eq!(res.err(), Some(IterExtdError::Overflow));
+
+    let iter = [0u64, 1, u64::MAX].into_iter().scaling_auto(..);
+    assert_eq!(iter.collect::<Vec<u64>>(), vec![0, 1, u64::MAX]);
+}
+
+#[test]
+fn test_scaling_auto_one_element_and_empty() {
+    let iter = [i8::MIN].into_iter().scaling_auto(5..=255u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![5]);
+
+    let iter = [1u32, 1, 1].into_iter().scaling_auto(5..=20u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![20, 20, 20]);
+
+    let arr: [u8; 0] = [];
+    assert_eq!(arr.into_iter().scaling_auto(..=9u8).count(), 0);
+}
+
+#[test]
+fn test_scaling_auto_iterator_traits() {
+    let iter = (0..9u32).scaling_auto(..=4u8);
+    assert_eq!(iter.len(), 9);
+    assert_eq!(iter.clone().rev().collect::<Vec<_>>(), vec![4, 3, 3, 2, 2, 1, 1, 0, 0]);
+    assert_eq!(iter.size_hint(), (9, Some(9)));
+}
+
+#[test]
+fn test_try_scaling_auto() {
+    let res = [0u64].into_iter().try_scaling_auto((10, 5u8));
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+}
+
+#[test]
+#[should_panic(expected = "lower range bound must be less than upper")]
+fn test_scaling_auto_invalid_range_panic() {
+    let _iter = [0i128].into_iter().scaling_auto((10, 5u8));
+}
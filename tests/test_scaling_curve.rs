@@ -0,0 +1,167 @@
+use iterextd::{IterExtdError, NonFinite, Rounding, Scaler};
+
+#[test]
+fn test_scaling_log() {
+    let levels = [1.0, 10.0, 100.0, 1000.0];
+    let iter = levels.into_iter().scaling_log(1.0..=1000.0, -60.0..=0.0f64, None, NonFinite::Skip);
+    let db = iter.collect::<Vec<_>>();
+    assert_eq!(db.len(), 4);
+    assert!(db.iter().zip([-60.0, -40.0, -20.0, 0.0]).all(|(a, b)| (a - b).abs() < 1e-9));
+
+    let arr = [0i32, -5, 1, 100];
+    let iter = arr.into_iter().scaling_log(1..=100, 10..=20u8, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 20]);
+    let iter = arr.into_iter().scaling_log(1..=100, 10..=20u8, Some(Rounding::Nearest), NonFinite::Clamp);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 10, 20]);
+    let iter = arr.into_iter().scaling_log(1..=100, 10..=20u8, Some(Rounding::Nearest), NonFinite::Clamp);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), vec![20, 10, 10]);
+
+    let iter = [7u16, 7].into_iter().scaling_log(7..=7, 1..=9u8, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 1]);
+}
+
+#[test]
+fn test_scaling_log_input_range() {
+    let iter = [10u16].into_iter().scaling_log(1..=100, 10..=20u8, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![15]);
+
+    let iter = [1u32, 10, 1_000_000].into_iter().scaling_log(10.., 0..=100u8, None, NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 0, 57]);
+}
+
+#[test]
+fn test_try_scaling_log() {
+    let res = [1.0].into_iter().try_scaling_log(0.0..=10.0, 0..=9u8, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let res = [1u8].into_iter().try_scaling_log(.., 0..=9u8, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let res = [1u8].into_iter().try_scaling_log((10, 1), 0..=9u8, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let res = [1u8].into_iter().try_scaling_log(1..=10, (9, 0u8), None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let iter = [1u8, 10].into_iter().try_scaling_log(1..=10, 0..=9u8, None, NonFinite::Skip).unwrap();
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 9]);
+}
+
+#[test]
+#[should_panic(expected = "the value to scale must be finite")]
+fn test_scaling_log_non_positive_panic() {
+    let _ = [0.0, 1.0].into_iter().scaling_log(1.0..=10.0, 0..=9u8, Some(Rounding::Nearest), NonFinite::Panic).count();
+}
+
+#[test]
+#[should_panic(expected = "the lower bound of the input range must be positive and less than upper")]
+fn test_scaling_log_invalid_input_range_panic() {
+    let _iter = [1u8].into_iter().scaling_log(0..=10, 0..=9u8, Some(Rounding::Nearest), NonFinite::Skip);
+}
+
+#[test]
+fn test_scaling_gamma() {
+    let iter = (0..=4u8).scaling_gamma(0..=16u8, 2.0, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 4, 9, 16]);
+
+    let iter = (0..=4u8).scaling_gamma(0..=16u8, 0.5, Some(Rounding::Floor), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 8, 11, 13, 16]);
+
+    let iter = (0..=4u8).scaling_gamma(0..=16u8, 1.0, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), (0..=4u8).scaling_float(0..=16u8, Some(Rounding::Nearest), NonFinite::Skip).collect::<Vec<_>>());
+
+    let iter = [-1.0f32, f32::NAN, 1.0].into_iter().scaling_gamma(-1.0..=1.0f32, 3.0, None, NonFinite::Skip);
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![-1.0, 1.0]);
+}
+
+#[test]
+fn test_try_scaling_gamma() {
+    let res = [0u8, 9].into_iter().try_scaling_gamma(..=9u8, 0.0, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidCurve));
+
+    let res = [0u8, 9].into_iter().try_scaling_gamma(..=9u8, f64::NAN, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidCurve));
+
+    let res = [0u8, 9].into_iter().try_scaling_gamma((9, 0u8), 2.0, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let iter = (0..=4u8).try_scaling_gamma(0..=16u8, 2.0, Some(Rounding::Nearest), NonFinite::Skip).unwrap();
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 4, 9, 16]);
+}
+
+#[test]
+#[should_panic(expected = "the gamma must be a positive finite number")]
+fn test_scaling_gamma_invalid_panic() {
+    let _iter = [0u8].into_iter().scaling_gamma(..=9u8, 0.0, Some(Rounding::Nearest), NonFinite::Skip);
+}
+
+#[test]
+fn test_scaling_piecewise() {
+    let curve = [(0.0, 0.0), (0.5, 0.8), (1.0, 1.0)];
+    let iter = (0..=4u8).scaling_piecewise(&curve, 0..=4, 0..=100u8, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 40, 80, 90, 100]);
+
+    let curve = [(0.25, 1.0), (0.75, 0.0)];
+    let iter = (0..=4i16).scaling_piecewise(&curve, 0..=4, -10..=10i8, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 10, 0, -10, -10]);
+
+    let curve = [(0.0, 0.0), (0.5, 0.0), (0.5, 1.0), (1.0, 1.0)];
+    let arr = [0.0, 0.49, 0.5, 1.0];
+    let iter = arr.into_iter().scaling_piecewise(&curve, 0.0..=1.0, 0..=1u8, Some(Rounding::Floor), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 0, 1, 1]);
+
+    let curve = [(0.0, 0.5)];
+    let iter = [1u8, 2].into_iter().scaling_piecewise(&curve, 1..=2, 0.0..=2.0f64, None, NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1.0, 1.0]);
+
+    let curve = [(0.0, -1.0), (1.0, 2.0)];
+    let iter = [1u8, 2].into_iter().scaling_piecewise(&curve, 1..=2, 0..=9u8, Some(Rounding::Floor), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 9]);
+}
+
+#[test]
+fn test_scaling_piecewise_input_range() {
+    let curve = [(0.0, 0.0), (0.5, 0.8), (1.0, 1.0)];
+    let iter = [2u8, 5].into_iter().scaling_piecewise(&curve, 0..=4, 0..=100u8, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![80, 100]);
+
+    let iter = [3u8].into_iter().scaling_piecewise(&curve, ..=4, 0..=100u8, None, NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![90]);
+}
+
+#[test]
+fn test_try_scaling_piecewise() {
+    let res = [0u8].into_iter().try_scaling_piecewise(&[], ..=9, 0..=9u8, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidCurve));
+
+    let curve = [(0.0, f64::NAN)];
+    let res = [0u8].into_iter().try_scaling_piecewise(&curve, ..=9, 0..=9u8, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidCurve));
+
+    let curve = [(0.0, 0.0), (1.0, 1.0)];
+    let res = [0u8].into_iter().try_scaling_piecewise(&curve, (9, 0), 0..=9u8, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let iter = [0u8, 9].into_iter().try_scaling_piecewise(&curve, ..=9, 0..=9u8, None, NonFinite::Skip).unwrap();
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 9]);
+}
+
+#[test]
+#[should_panic(expected = "the points must be finite and sorted by input")]
+fn test_scaling_piecewise_unsorted_panic() {
+    let curve = [(0.5, 0.0), (0.0, 1.0)];
+    let _iter = [0u8].into_iter().scaling_piecewise(&curve, ..=9, ..=9u8, Some(Rounding::Nearest), NonFinite::Skip);
+}
+
+#[test]
+#[should_panic(expected = "the points must be finite and sorted by input")]
+fn test_scaling_piecewise_empty_panic() {
+    let _iter = [0u8].into_iter().scaling_piecewise(&[], ..=9, ..=9u8, Some(Rounding::Nearest), NonFinite::Skip);
+}
+
+#[test]
+#[should_panic(expected = "lower range bound must be less than upper")]
+fn test_scaling_curve_invalid_range_panic() {
+    let _iter = [0u8].into_iter().scaling_gamma((9, 0u8), 2.0, Some(Rounding::Nearest), NonFinite::Skip);
+}
@@ -0,0 +1,108 @@
+use iterextd::{IterExtdError, NonFinite, Rounding, Scaler};
+
+#[test]
+fn test_scaling_float_to_int_rounding() {
+    let arr = [0.0, 0.1, 0.5, 1.0];
+    let iter = arr.into_iter().scaling_float(0..=10u8, Some(Rounding::Floor), NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 5, 10]);
+
+    let arr = [0.0, 0.01, 0.05, 0.94, 1.0];
+    let iter = arr.into_iter().scaling_float(0..=10u8, Some(Rounding::Floor), NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 0, 0, 9, 10]);
+    let iter = arr.into_iter().scaling_float(0..=10u8, Some(Rounding::Nearest), NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 0, 1, 9, 10]);
+    let iter = arr.into_iter().scaling_float(0..=10u8, Some(Rounding::Ceil), NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 1, 10, 10]);
+    let iter = arr.into_iter().scaling_float(0..=10u8, None, NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 0, 0, 9, 10]);
+
+    let arr = [0.0, 0.33, 1.0];
+    let iter = arr.into_iter().scaling_float(-10..=10i8, None, NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![-10, -4, 10]);
+
+    let arr = [-1.0f32, 0.0, 1.0];
+    let iter = arr.into_iter().scaling_float(.., Some(Rounding::Nearest), NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<i8>>(), vec![i8::MIN, 0, i8::MAX]);
+
+    let arr = [0.0, 1.0];
+    let iter = arr.into_iter().scaling_float(.., Some(Rounding::Nearest), NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<u64>>(), vec![0, u64::MAX]);
+    let iter = arr.into_iter().scaling_float(.., Some(Rounding::Nearest), NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<i128>>(), vec![i128::MIN, i128::MAX]);
+}
+
+#[test]
+fn test_scaling_float_int_to_float() {
+    let iter = (0..=4u16).scaling_float(-1.0..=1.0f64, None, NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+
+    let iter = [i64::MIN, i64::MAX].into_iter().scaling_float(0.0..=1.0f32, None, NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0.0, 1.0]);
+
+    let iter = [0.0f64, 1.0].into_iter().scaling_float(.., None, NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<f64>>(), vec![f64::MIN, f64::MAX]);
+
+    let iter = [f64::MIN, 0.0, f64::MAX].into_iter().scaling_float(0.0..=2.0f64, None, NonFinite::Panic);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0.0, 1.0, 2.0]);
+}
+
+#[test]
+fn test_scaling_float_non_finite() {
+    let arr = [f64::NEG_INFINITY, 1.0, f64::NAN, 3.0, f64::INFINITY];
+    let iter = arr.into_iter().scaling_float(10..=20i32, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 20]);
+
+    let iter = arr.into_iter().scaling_float(10..=20i32, Some(Rounding::Nearest), NonFinite::Clamp);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 10, 20, 20]);
+
+    let iter = arr.into_iter().scaling_float(10..=20i32, Some(Rounding::Nearest), NonFinite::Clamp);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), vec![20, 20, 10, 10]);
+
+    let arr = [f32::NAN, f32::INFINITY];
+    let iter = arr.into_iter().scaling_float(10..=20u8, Some(Rounding::Nearest), NonFinite::Clamp);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![20]);
+}
+
+#[test]
+#[should_panic(expected = "the value to scale must be finite")]
+fn test_scaling_float_non_finite_panic() {
+    let arr = [0.0, f64::NAN, 1.0];
+    let _ = arr.into_iter().scaling_float(0..=10u8, Some(Rounding::Nearest), NonFinite::Panic).count();
+}
+
+#[test]
+fn test_scaling_float_equal_and_empty() {
+    let iter = [2.5, 2.5].into_iter().scaling_float(3..=7u8, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![3, 3]);
+
+    let arr: [f32; 0] = [];
+    let iter = arr.into_iter().scaling_float(3..=7u8, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.count(), 0);
+}
+
+#[test]
+fn test_scaling_float_size_hint() {
+    let arr = [0.0, 1.0, 2.0];
+    let iter = arr.into_iter().scaling_float(0..=2u8, Some(Rounding::Nearest), NonFinite::Panic);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    let iter = arr.into_iter().scaling_float(0..=2u8, Some(Rounding::Nearest), NonFinite::Skip);
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+}
+
+#[test]
+fn test_try_scaling_float() {
+    let res = [0.5].into_iter().try_scaling_float((5, 1u8), Some(Rounding::Floor), NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let res = [0.5].into_iter().try_scaling_float(f64::NAN..=1.0, None, NonFinite::Skip);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let iter = [0.5, 1.5].into_iter().try_scaling_float(1..=5u8, Some(Rounding::Floor), NonFinite::Skip).unwrap();
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 5]);
+}
+
+#[test]
+#[should_panic(expected = "lower range bound must be less than upper")]
+fn test_scaling_float_invalid_range_panic() {
+    let _iter = [0.5].into_iter().scaling_float(1.0..=0.0f32, None, NonFinite::Skip);
+}
@@ -0,0 +1,94 @@
+use iterextd::{IterExtdError, Scaler};
+
+#[test]
+fn test_scaling_from_identity() {
+    let iter = (i8::MIN..=i8::MAX).scaling_from::<u16>(.., ..);
+    assert_eq!(iter.collect::<Vec<i8>>(), (i8::MIN..=i8::MAX).collect::<Vec<_>>());
+
+    let iter = (u8::MIN..=u8::MAX).scaling_from::<u16>(.., i8::MIN..);
+    assert_eq!(iter.map(|x: i8| x as u8 ^ 0x80).collect::<Vec<_>>(), (u8::MIN..=u8::MAX).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_scaling_from_adc() {
+    let adc = [0u16, 1, 2047, 2048, 4094, 4095];
+    let iter = adc.into_iter().scaling_from::<u32>(..=4095u16, ..=255u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 0, 127, 127, 254, 255]);
+
+    let iter = adc.into_iter().scaling_from::<u32>(..=4095u16, -100..=100i8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![-100, -100, -1, 0, 99, 100]);
+
+    let iter = [-50i32, 0, 50].into_iter().scaling_from::<u64>(-50..=50, 1000..=2000u16);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1000, 1500, 2000]);
+}
+
+#[test]
+fn test_scaling_from_clamp() {
+    let iter = [-10i16, 0, 5, 10, 20].into_iter().scaling_from::<u32>(0..=10, 10..=20u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 10, 15, 20, 20]);
+
+    let iter = [1u8, 3, 5].into_iter().scaling_from::<u16>((3, 3), 0..=9u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 0, 9]);
+}
+
+#[test]
+fn test_scaling_from_single_pass() {
+    let mut source = [0u8, 64, 128, 255].into_iter();
+    let single_pass = core::iter::from_fn(move || source.next());
+    let iter = single_pass.scaling_from::<u16>(.., ..=100u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 25, 50, 100]);
+}
+
+#[test]
+fn test_scaling_from_iterator_traits() {
+    let iter = (0..=8u8).scaling_from::<u16>(..=8, ..=4u8);
+    assert_eq!(iter.len(), 9);
+    assert_eq!(iter.size_hint(), (9, Some(9)));
+    assert_eq!(iter.rev().collect::<Vec<_>>(), vec![4, 3, 3, 2, 2, 1, 1, 0, 0]);
+}
+
+#[test]
+fn test_scaling_from_checked() {
+    let iter = [-1i16, 0, 50, 100, 101].into_iter().scaling_from_checked::<u32>(0..=100, 0..=10u8);
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        vec![Err(IterExtdError::OutOfBounds), Ok(0), Ok(5), Ok(10), Err(IterExtdError::OutOfBounds)]
+    );
+
+    let iter = [0u8, 200].into_iter().scaling_from_checked::<u16>(..=100, ..=10u8);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), vec![Err(IterExtdError::OutOfBounds), Ok(0)]);
+
+    let iter = [0u8, 10].into_iter().scaling_from_checked::<u16>(..=10, ..=5u8);
+    assert_eq!(iter.collect::<Result<Vec<_>, _>>(), Ok(vec![0, 5]));
+}
+
+#[test]
+fn test_try_scaling_from() {
+    let res = [0u8].into_iter().try_scaling_from::<u16>((10, 5), ..=255u8);
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let res = [0u8].into_iter().try_scaling_from::<u16>(.., (5, 0u8));
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let res = [0u16].into_iter().try_scaling_from::<u8>(.., ..=1u8);
+    assert_eq!(res.err(), Some(IterExtdError::Overflow));
+
+    let res = [0u16].into_iter().try_scaling_from::<u16>(.., ..=2u8);
+    assert_eq!(res.err(), Some(IterExtdError::Overflow));
+
+    let iter = [0u16, u16::MAX].into_iter().try_scaling_from::<u32>(.., ..).unwrap();
+    assert_eq!(iter.collect::<Vec<u16>>(), vec![0, u16::MAX]);
+}
+
+#[test]
+#[should_panic(expected = "lower range bound must be less than upper")]
+fn test_scaling_from_invalid_range_panic() {
+    let _iter = [0u8].into_iter().scaling_from::<u16>((10, 5), ..=255u8);
+}
+
+#[test]
+#[should_panic(expected = "overflow of the selected intermediate type")]
+fn test_scaling_from_overflow_panic() {
+    let _iter = [0u8].into_iter().scaling_from::<u8>(.., ..=255u8);
+}
@@ -45,8 +45,8 @@ fn test_scaling_debug() {
     let iter = [0u8, 1, 2].into_iter().scaling::<u16>(0..=25u8);
     assert_eq!(
         format!("{:?}", iter),
-        "Scaling { denominator: 2, numerator: 25, input_start: 0, \
-        output_start: 0, iter: IntoIter([0, 1, 2]), phantom: PhantomData<u8> }"
+        "Scaling { map: ScaleMap { input_start: 0, input_end: 2, output_start: 0, \
+        output_end: 25, phantom: PhantomData<u8> }, iter: IntoIter([0, 1, 2]) }"
     );
 }
 
@@ -128,6 +128,21 @@ fn test_scaling_one_element_zero() {
     assert_eq!(result, vec![255]);
 }
 
+#[test]
+fn test_scaling_equal_signed_intermediate() {
+    let iter = [-5i8, -5].into_iter().scaling::<i16>(0..=10u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 10]);
+
+    let iter = [-5i8].into_iter().scaling::<i16>(-10..=10i8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10]);
+
+    let iter = [5i8, 5].into_iter().scaling::<i16>(0..=10u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10, 10]);
+
+    let iter = [0i8, 0].into_iter().scaling::<i16>(0..=10u8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 0]);
+}
+
 #[test]
 fn test_scaling_one_elements_bounds() {
     let iter = [0u8].into_iter().scaling::<u32>(..u8::MAX);
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use iterextd::IterExtd;
+
+    const A: [i32; 6] = [-4, 1, 3, 5, 7, 9];
+    const B: [i32; 5] = [-5, 2, 3, 4, 9];
+
+    #[test]
+    fn test_union_sorted() {
+        let vec = A.into_iter().union_sorted(B).collect::<Vec<_>>();
+        assert_eq!(vec, vec![-5, -4, 1, 2, 3, 4, 5, 7, 9]);
+        let vec = B.iter().union_sorted(A.iter()).copied().collect::<Vec<_>>();
+        assert_eq!(vec, vec![-5, -4, 1, 2, 3, 4, 5, 7, 9]);
+        let vec = A.into_iter().union_sorted([]).collect::<Vec<_>>();
+        assert_eq!(vec, A);
+        let vec = [].into_iter().union_sorted(B).collect::<Vec<_>>();
+        assert_eq!(vec, B);
+
+        let iter = A.into_iter().union_sorted(B);
+        assert_eq!(iter.size_hint(), (6, Some(11)));
+    }
+
+    #[test]
+    fn test_intersect_sorted() {
+        let vec = A.into_iter().intersect_sorted(B).collect::<Vec<_>>();
+        assert_eq!(vec, vec![3, 9]);
+        let vec = B.into_iter().intersect_sorted(A).collect::<Vec<_>>();
+        assert_eq!(vec, vec![3, 9]);
+        let vec = A.into_iter().intersect_sorted([]).collect::<Vec<_>>();
+        assert_eq!(vec, vec![]);
+
+        let iter = A.into_iter().intersect_sorted(B);
+        assert_eq!(iter.size_hint(), (0, Some(5)));
+
+        let vec = (0..).step_by(2).intersect_sorted((0..).step_by(3)).take(4).collect::<Vec<_>>();
+        assert_eq!(vec, vec![0, 6, 12, 18]);
+    }
+
+    #[test]
+    fn test_difference_sorted() {
+        let vec = A.into_iter().difference_sorted(B).collect::<Vec<_>>();
+        assert_eq!(vec, vec![-4, 1, 5, 7]);
+        let vec = B.into_iter().difference_sorted(A).collect::<Vec<_>>();
+        assert_eq!(vec, vec![-5, 2, 4]);
+        let vec = A.into_iter().difference_sorted([]).collect::<Vec<_>>();
+        assert_eq!(vec, A);
+        let vec = [].into_iter().difference_sorted(A).collect::<Vec<_>>();
+        assert_eq!(vec, vec![]);
+
+        let iter = A.into_iter().difference_sorted([3]);
+        assert_eq!(iter.size_hint(), (5, Some(6)));
+    }
+
+    #[test]
+    fn test_symmetric_difference_sorted() {
+        let vec = A.into_iter().symmetric_difference_sorted(B).collect::<Vec<_>>();
+        assert_eq!(vec, vec![-5, -4, 1, 2, 4, 5, 7]);
+        let vec = B.into_iter().symmetric_difference_sorted(A).collect::<Vec<_>>();
+        assert_eq!(vec, vec![-5, -4, 1, 2, 4, 5, 7]);
+        let vec = A.into_iter().symmetric_difference_sorted(A).collect::<Vec<_>>();
+        assert_eq!(vec, vec![]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_set_ops_sorted_with_unique_sorted() {
+        let arr = [9u8, 5, 6, 4, 8, 8, 2, 4, 10, 2, 12];
+        let other = [12u8, 1, 2, 2, 3];
+        let vec = arr
+            .iter()
+            .unique_sorted()
+            .union_sorted(other.iter().unique_sorted())
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![1, 2, 3, 4, 5, 6, 8, 9, 10, 12]);
+
+        let iter = A.into_iter().union_sorted(B);
+        let iter_clone = iter.clone();
+        assert_eq!(iter.count(), iter_clone.count());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "the iterator must be sorted")]
+    fn test_set_ops_sorted_unsorted() {
+        let _ = [1, 3, 2].into_iter().union_sorted([2, 4]).count();
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use iterextd::{IterExtd, Stats};
+
+    #[test]
+    fn test_stats() {
+        let stats = [2_u8, 4, 4, 4, 5, 5, 7, 9].into_iter().stats();
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.sum(), Some(40));
+        assert_eq!((stats.min(), stats.max()), (Some(2), Some(9)));
+        assert_eq!(stats.mean(), Some(5.0));
+        assert_eq!(stats.variance(), Some(4.0));
+        assert_relative_eq!(stats.sample_variance().unwrap(), 32.0 / 7.0);
+
+        let stats = [-1.5, 2.5, 0.5].into_iter().stats();
+        assert_eq!(stats.sum(), Some(1.5));
+        assert_eq!((stats.min(), stats.max()), (Some(-1.5), Some(2.5)));
+        assert_relative_eq!(stats.mean().unwrap(), 0.5);
+        assert_relative_eq!(stats.variance().unwrap(), 8.0 / 3.0);
+
+        let stats = [7_i64].into_iter().stats();
+        assert_eq!(stats.variance(), Some(0.0));
+        assert_eq!(stats.sample_variance(), None);
+
+        let stats = [0_i32; 0].into_iter().stats();
+        assert_eq!(stats, Stats::default());
+        assert_eq!((stats.count(), stats.sum()), (0, Some(0)));
+        assert_eq!((stats.min(), stats.max(), stats.mean(), stats.variance()), (None, None, None, None));
+
+        let stats = [f64::NAN, 1.0, 3.0].into_iter().stats();
+        assert_eq!((stats.min(), stats.max()), (Some(1.0), Some(3.0)));
+        assert!(stats.mean().unwrap().is_nan());
+        assert!(stats.sum().unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_stats_sum_wide() {
+        let stats = [200_u8, 100].into_iter().stats();
+        assert_eq!(stats.sum(), None);
+        assert_eq!(stats.mean(), Some(150.0));
+
+        let stats = [100_i8, 100, -100].into_iter().stats();
+        assert_eq!(stats.sum(), Some(100));
+
+        let stats = [u64::MAX, u64::MAX, 1].into_iter().stats();
+        assert_eq!(stats.sum(), None);
+
+        let stats = [i128::MAX, 1].into_iter().stats();
+        assert_eq!(stats.sum(), None);
+
+        let stats = [u128::MAX].into_iter().stats();
+        assert_eq!((stats.count(), stats.sum()), (1, Some(u128::MAX)));
+
+        let stats = [u128::MAX - 1, 0].into_iter().stats();
+        assert_eq!(stats.sum(), Some(u128::MAX - 1));
+
+        let stats = [u128::MAX, 1].into_iter().stats();
+        assert_eq!(stats.sum(), None);
+
+        let stats = [i128::MIN, -1].into_iter().stats();
+        assert_eq!(stats.sum(), None);
+
+        let stats = [f32::MAX, f32::MAX, -f32::MAX].into_iter().stats();
+        assert_eq!(stats.sum(), Some(f32::MAX));
+    }
+
+    #[test]
+    fn test_stats_push_extend() {
+        let mut stats = Stats::new();
+        stats.push(1_u32);
+        stats.extend([2, 3]);
+        assert_eq!(stats, [1_u32, 2, 3].into_iter().stats());
+        assert_eq!(stats.mean(), Some(2.0));
+
+        let mut stats = Stats::new();
+        stats.extend([1e9_f64 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+        assert_relative_eq!(stats.variance().unwrap(), 22.5);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_median() {
+        assert_eq!([3, 1, 2].into_iter().median(), Some(2.0));
+        assert_eq!([4_u8, 1, 3, 2].into_iter().median(), Some(2.5));
+        assert_eq!([f64::NAN, 2.0, f64::NAN].into_iter().median(), Some(2.0));
+        assert_eq!([i8::MIN, i8::MAX].into_iter().median(), Some(-0.5));
+        assert_eq!([0_i32; 0].into_iter().median(), None);
+        assert_eq!([f32::NAN].into_iter().median(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_percentile() {
+        let arr = [50, 40, 35, 20, 15];
+        assert_eq!(arr.into_iter().percentile(0.0), Some(15.0));
+        assert_eq!(arr.into_iter().percentile(25.0), Some(20.0));
+        assert_eq!(arr.into_iter().percentile(40.0), Some(29.0));
+        assert_eq!(arr.into_iter().percentile(90.0), Some(46.0));
+        assert_eq!(arr.into_iter().percentile(100.0), Some(50.0));
+        assert_eq!([7.5].into_iter().percentile(99.0), Some(7.5));
+        assert_eq!((1..=100).percentile(50.0), Some(50.5));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "the percentile must be in the range 0.0..=100.0")]
+    fn test_percentile_out_of_range() {
+        let _ = [1, 2].into_iter().percentile(100.5);
+    }
+}
@@ -0,0 +1,240 @@
+#[cfg(feature = "alloc")]
+use iterextd::{Flip2D, Rotation2D};
+use iterextd::{Iter2D, IterExtd, IterExtdError, Scaler, SliceModifyIter};
+use std::iter;
+use std::rc::Rc;
+
+#[test]
+fn test_try_overlay_2d() {
+    assert_eq!(
+        (0..9).try_overlay_2d(0..4, (3, 0), (2, 2), (0, 0)).err(),
+        Some(IterExtdError::ZeroSize)
+    );
+    assert_eq!(
+        (0..9).try_overlay_2d(0..4, (3, 3), (0, 2), (0, 0)).err(),
+        Some(IterExtdError::ZeroSize)
+    );
+    assert_eq!(
+        (0..9).try_overlay_2d(0..4, (3, 3), (2, 2), (2, 0)).err(),
+        Some(IterExtdError::OutOfBounds)
+    );
+    assert_eq!(
+        (0..9).try_overlay_2d(0..4, (3, 3), (2, 2), (0, 2)).err(),
+        Some(IterExtdError::OutOfBounds)
+    );
+
+    let res_vec = (0..9)
+        .try_overlay_2d(10..14, (3, 3), (2, 2), (1, 1))
+        .unwrap()
+        .collect::<Vec<_>>();
+    let expected = (0..9)
+        .overlay_2d(10..14, (3, 3), (2, 2), (1, 1))
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, expected);
+}
+
+#[test]
+fn test_try_crop_2d() {
+    assert_eq!(
+        (0..9).try_crop_2d((0, 3), (2, 2), (0, 0)).err(),
+        Some(IterExtdError::ZeroSize)
+    );
+    assert_eq!(
+        (0..9).try_crop_2d((3, 3), (2, 2), (1, 2)).err(),
+        Some(IterExtdError::OutOfBounds)
+    );
+
+    let res_vec = (0..9)
+        .try_crop_2d((3, 3), (2, 2), (1, 1))
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![4, 5, 7, 8]);
+}
+
+#[test]
+fn test_try_overlay_2d_variants() {
+    assert_eq!(
+        (0..9).try_overlay_2d_with(0..4, (3, 3), (2, 0), (0, 0), |b, o| b + o).err(),
+        Some(IterExtdError::ZeroSize)
+    );
+    assert_eq!(
+        (0..9).try_overlay_2d_with(0..4, (3, 3), (2, 2), (usize::MAX, 0), |b, o| b + o).err(),
+        Some(IterExtdError::OutOfBounds)
+    );
+    assert_eq!(
+        (0..9).try_overlay_2d_transparent(iter::empty(), (3, 3), (4, 1), (0, 0)).err(),
+        Some(IterExtdError::OutOfBounds)
+    );
+    assert_eq!(
+        (0..9).try_overlay_2d_clipped(0..4, (0, 3), (2, 2), (-1, -1)).err(),
+        Some(IterExtdError::ZeroSize)
+    );
+    assert_eq!(
+        (0..9).try_overlay_2d_clipped(0..4, (3, 3), (usize::MAX, 2), (-1, -1)).err(),
+        Some(IterExtdError::Overflow)
+    );
+
+    let res_vec = (0..9)
+        .try_overlay_2d_with(10..14, (3, 3), (2, 2), (1, 1), |b, o| b + o)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![0, 1, 2, 3, 14, 16, 6, 19, 21]);
+
+    let res_vec = (0..9)
+        .try_overlay_2d_clipped(10..14, (3, 3), (2, 2), (-1, -1))
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![13, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_try_remap_2d() {
+    assert_eq!((0..6).try_transpose_2d((0, 2)).err(), Some(IterExtdError::ZeroSize));
+    assert_eq!(
+        (0..6).try_rotate_2d((usize::MAX, 2), Rotation2D::Deg90).err(),
+        Some(IterExtdError::Overflow)
+    );
+    assert_eq!(
+        (0..6).try_flip_2d((3, 0), Flip2D::Horizontal).err(),
+        Some(IterExtdError::ZeroSize)
+    );
+    assert_eq!((0..6).try_tile_2d((3, 2), (0, 1)).err(), Some(IterExtdError::ZeroSize));
+    assert_eq!(
+        (0..6).try_tile_2d((3, 2), (1, usize::MAX)).err(),
+        Some(IterExtdError::Overflow)
+    );
+
+    let res_vec = (0..6)
+        .try_rotate_2d((3, 2), Rotation2D::Deg270)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(res_vec, vec![2, 5, 1, 4, 0, 3]);
+}
+
+#[test]
+fn test_try_collect_array() {
+    assert_eq!((0..5).try_collect_array::<5>().ok(), Some([0, 1, 2, 3, 4]));
+    assert_eq!((0..5).try_collect_array::<0>().ok(), Some([]));
+
+    let partial = (0..5).try_collect_array::<6>().unwrap_err();
+    assert_eq!(partial.len(), 5);
+    assert_eq!(partial.as_slice(), &[0, 1, 2, 3, 4]);
+    assert_eq!(format!("{:?}", partial), "PartialArray([0, 1, 2, 3, 4])");
+    assert_eq!(partial.to_string(), "iterator yielded 5 of 6 elements");
+    assert_eq!(partial.rev().collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+
+    let partial = (0..0).try_collect_array::<3>().unwrap_err();
+    assert!(partial.is_empty());
+
+    let vec = vec!["a".to_string(), "b".to_string()];
+    let arr = vec.into_iter().try_collect_array::<2>();
+    assert_eq!(arr.ok(), Some(["a".to_string(), "b".to_string()]));
+
+    // Only N elements are taken.
+    let mut iter = 0..5;
+    assert_eq!(iter.by_ref().try_collect_array::<2>().ok(), Some([0, 1]));
+    assert_eq!(iter.next(), Some(2));
+}
+
+#[test]
+fn test_try_collect_array_partial_drop() {
+    let rc = Rc::new(());
+    let partial = iter::repeat(rc.clone())
+        .take(3)
+        .try_collect_array::<5>()
+        .unwrap_err();
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    let mut cloned = partial.clone();
+    assert_eq!(Rc::strong_count(&rc), 7);
+    assert_eq!(cloned.len(), 3);
+    let _ = cloned.next();
+    assert_eq!(cloned.len(), 2);
+    drop(cloned);
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    drop(partial);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let arr = iter::repeat(rc.clone()).take(3).try_collect_array::<3>();
+    assert_eq!(Rc::strong_count(&rc), 4);
+    drop(arr);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_try_collect_array_into_error() {
+    fn first_pair(iter: impl Iterator<Item = u8>) -> Result<[u8; 2], IterExtdError> {
+        Ok(iter.try_collect_array::<2>()?)
+    }
+
+    assert_eq!(first_pair([1, 2, 3].into_iter()), Ok([1, 2]));
+    assert_eq!(first_pair([1].into_iter()), Err(IterExtdError::TooShort));
+}
+
+#[test]
+fn test_try_step_adapters() {
+    assert_eq!((0..5).try_step_boundary(0).err(), Some(IterExtdError::ZeroSize));
+    assert_eq!((0..5).try_inclusive_step_by(0).err(), Some(IterExtdError::ZeroSize));
+    assert_eq!(
+        (0..5).try_combine_iters(0, 0..5, 0).err().unwrap(),
+        IterExtdError::ZeroSize
+    );
+
+    let vec = (0..5).try_step_boundary(2).unwrap().collect::<Vec<_>>();
+    assert_eq!(vec, vec![(0, 1), (2, 3), (4, 4)]);
+
+    let vec = (0..5).try_inclusive_step_by(3).unwrap().collect::<Vec<_>>();
+    assert_eq!(vec, vec![0, 3, 4]);
+
+    let vec = (0..3)
+        .try_combine_iters(0, 10..13, 1)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(vec, vec![10, 11, 12]);
+}
+
+#[test]
+fn test_try_gen_bounds() {
+    let arr = [0u8; 7];
+    assert_eq!(arr.try_gen_range_bounds(0).err(), Some(IterExtdError::ZeroSize));
+    assert_eq!(arr.try_gen_tuple_bounds(0).err(), Some(IterExtdError::ZeroSize));
+
+    let vec = arr.try_gen_range_bounds(3).unwrap().collect::<Vec<_>>();
+    assert_eq!(vec, vec![0..=2, 3..=5, 6..=6]);
+
+    let vec = arr.try_gen_tuple_bounds(3).unwrap().collect::<Vec<_>>();
+    assert_eq!(vec, vec![(0, 2), (3, 5), (6, 6)]);
+}
+
+#[test]
+fn test_try_scaling() {
+    let res = [0u8, 200].into_iter().try_scaling::<u8>(..=200u8);
+    assert_eq!(res.err(), Some(IterExtdError::Overflow));
+
+    let res = [0u16, 300].into_iter().try_scaling::<u8>(..=10u8);
+    assert_eq!(res.err(), Some(IterExtdError::Overflow));
+
+    let res = [0u8, 20].into_iter().try_scaling::<u16>((10u8, 5u8));
+    assert_eq!(res.err(), Some(IterExtdError::InvalidRange));
+
+    let iter = [0u8, 1, 2].into_iter().try_scaling::<u16>(0..=20u8).unwrap();
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 10, 20]);
+}
+
+#[test]
+fn test_iterextd_error_display() {
+    assert_eq!(IterExtdError::ZeroSize.to_string(), "size must be non-zero");
+    assert_eq!(
+        IterExtdError::Overflow.to_string(),
+        "overflow of the selected intermediate type"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_iterextd_error_trait_object() {
+    let err: Box<dyn std::error::Error> = Box::new(IterExtdError::TooShort);
+    assert_eq!(err.to_string(), "iterator is shorter than required");
+}
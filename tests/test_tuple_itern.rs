@@ -1,3 +1,5 @@
+#![cfg(feature = "itern")]
+
 use iterextd::TupleItern;
 
 // Helper function to compare tuples for equality
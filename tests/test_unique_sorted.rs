@@ -1,6 +1,8 @@
+#![cfg(feature = "alloc")]
+
 #[cfg(test)]
 mod tests {
-    use iterextd::IterExtd;
+    use iterextd::{IterExtd, UniqueSortedStrategy};
 
     #[test]
     fn test_unique_sorted_int_signed() {
@@ -40,4 +42,69 @@ mod tests {
         let vec = vec![4u16, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6, 7, 7, 8];
         assert_eq!(vec.iter().unique_sorted().collect::<Vec<_>>(), vec![4, 5, 6, 7, 8]);
     }
+
+    #[test]
+    fn test_unique_sorted_wide_range() {
+        let arr = [u64::MAX, 0, 1 << 40, u64::MAX, 7];
+        let iter = arr.iter().unique_sorted();
+        assert_eq!(iter.strategy(), UniqueSortedStrategy::SortDedup);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 7, 1 << 40, u64::MAX]);
+        assert_eq!(arr.iter().unique_sorted().rev().collect::<Vec<_>>(), vec![u64::MAX, 1 << 40, 7, 0]);
+
+        let arr = [i64::MIN, i64::MAX, -1, 0, -1];
+        assert_eq!(arr.iter().unique_sorted().collect::<Vec<_>>(), vec![i64::MIN, -1, 0, i64::MAX]);
+
+        let arr = [i128::MAX, i128::MIN, 0, i128::MIN, -1];
+        let iter = arr.iter().unique_sorted();
+        assert_eq!(iter.strategy(), UniqueSortedStrategy::SortDedup);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![i128::MIN, -1, 0, i128::MAX]);
+
+        let arr = [u128::MAX, u128::MAX - 2, u128::MAX - 1, u128::MAX];
+        let iter = arr.iter().unique_sorted();
+        assert_eq!(iter.strategy(), UniqueSortedStrategy::Bitset);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![u128::MAX - 2, u128::MAX - 1, u128::MAX]);
+
+        let arr = [-3i128, 5, -3, 1];
+        let iter = arr.iter().unique_sorted();
+        assert_eq!(iter.strategy(), UniqueSortedStrategy::Bitset);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![5, 1, -3]);
+    }
+
+    #[test]
+    fn test_unique_sorted_strategy() {
+        let vec = (0..1000u32).rev().map(|x| x * 7 % 1000).collect::<Vec<_>>();
+        let iter = vec.iter().unique_sorted();
+        assert_eq!(iter.strategy(), UniqueSortedStrategy::Bitset);
+        let expected = (0..1000).collect::<Vec<_>>();
+        assert_eq!(iter.collect::<Vec<_>>(), expected);
+
+        let iter = vec.iter().unique_sorted_with(UniqueSortedStrategy::SortDedup);
+        assert_eq!(iter.strategy(), UniqueSortedStrategy::SortDedup);
+        assert_eq!(iter.size_hint(), (1000, Some(1000)));
+        assert_eq!(iter.collect::<Vec<_>>(), expected);
+
+        let arr = [0u32, 1_000_000];
+        assert_eq!(arr.iter().unique_sorted().strategy(), UniqueSortedStrategy::SortDedup);
+        let iter = arr.iter().unique_sorted_with(UniqueSortedStrategy::Bitset);
+        assert_eq!(iter.strategy(), UniqueSortedStrategy::Bitset);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1_000_000]);
+
+        let arr = [0u128, u128::MAX];
+        let iter = arr.iter().unique_sorted_with(UniqueSortedStrategy::Bitset);
+        assert_eq!(iter.strategy(), UniqueSortedStrategy::SortDedup);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, u128::MAX]);
+
+        let arr = [1u64 << 40, 0, 1 << 40];
+        let iter = arr.iter().unique_sorted_with(UniqueSortedStrategy::Bitset);
+        assert_eq!(iter.strategy(), UniqueSortedStrategy::SortDedup);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1 << 40]);
+
+        let arr = [i8::MAX, i8::MIN, -1, i8::MAX];
+        let iter = arr.iter().unique_sorted_with(UniqueSortedStrategy::SortDedup);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![i8::MAX, -1, i8::MIN]);
+
+        let arr: [i8; 0] = [];
+        let iter = arr.iter().unique_sorted_with(UniqueSortedStrategy::SortDedup);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![]);
+    }
 }